@@ -0,0 +1,53 @@
+// Measures the lookup tables of the flying kings: their size, the time to build them
+// and the move generation in positions with kings.
+// Run with `cargo bench --bench king_moves`, add `--features flood_fill` to measure the generator without tables.
+use checkers_cpu::game::magic_numbers::{MOVES_WITHOUT_CAPTURES, MOVES_WITH_CAPTURES};
+use checkers_cpu::game::{Game, Ruleset};
+use std::hint::black_box;
+use std::time::Instant;
+
+const GAMES: usize = 300;
+const REPEATS: usize = 100;
+const RUNS: usize = 5;
+
+// Positions with kings from random games.
+fn collect_positions() -> Vec<Game> {
+    let mut rng = fastrand::Rng::with_seed(1);
+    let mut positions = Vec::new();
+    for _ in 0..GAMES {
+        let mut game = Game::new(Ruleset::Russian);
+        loop {
+            let moves = game.get_moves();
+            if moves.is_empty() || game.is_draw() {
+                break;
+            }
+            if game.is_queen != 0 {
+                positions.push(game.clone());
+            }
+            game.make_move(moves[rng.usize(..moves.len())]);
+            game.change_player();
+        }
+    }
+    positions
+}
+
+fn main() {
+    let start = Instant::now();
+    let entries = MOVES_WITHOUT_CAPTURES.len() + MOVES_WITH_CAPTURES.len();
+    let build_time = start.elapsed().as_secs_f64();
+    println!("Tables: {entries} entries, {:.2} MB, built in {:.1} ms", (entries * 8) as f64 / 1e6, build_time * 1e3);
+
+    let positions = collect_positions();
+    let mut best = f64::INFINITY;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        for _ in 0..REPEATS {
+            for game in &positions {
+                black_box(black_box(game).get_moves().len());
+            }
+        }
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    let calls = (positions.len() * REPEATS) as f64;
+    println!("{} positions with kings: {:.1} ns/position", positions.len(), best / calls * 1e9);
+}
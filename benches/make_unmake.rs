@@ -0,0 +1,67 @@
+// Compares the speed of copying the position for every child node with making and taking back moves in place.
+// Run with `cargo bench --bench make_unmake`.
+use checkers_cpu::game::{Game, InternationalGame, Position, Ruleset, TurkishGame};
+use std::time::Instant;
+
+const DEPTH: u32 = 8;
+// The 10x10 and Turkish positions have more moves.
+const LARGE_BOARD_DEPTH: u32 = 6;
+
+fn perft_with_clone<G: Position>(game: &G, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    game.get_moves().into_iter().map(|m| {
+        let mut game_copy = game.clone();
+        game_copy.make_move(m);
+        game_copy.change_player();
+        perft_with_clone(&game_copy, depth - 1)
+    }).sum()
+}
+
+fn perft_with_unmake<G: Position>(game: &mut G, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let mut nodes = 0;
+    for m in game.get_moves() {
+        let undo = game.make_move(m);
+        game.change_player();
+        nodes += perft_with_unmake(game, depth - 1);
+        game.unmake_move(undo);
+    }
+    nodes
+}
+
+fn compare<G: Position>(name: &str, game: G, depth: u32) {
+    // Warm up: the first access builds the magic tables.
+    perft_with_clone(&game, 4);
+
+    // The best of several runs, to reduce the noise.
+    let mut clone_time = f64::INFINITY;
+    let mut unmake_time = f64::INFINITY;
+    let mut nodes = 0;
+    for _ in 0..3 {
+        let start = Instant::now();
+        nodes = perft_with_clone(&game, depth);
+        clone_time = clone_time.min(start.elapsed().as_secs_f64());
+
+        let start = Instant::now();
+        let unmake_nodes = perft_with_unmake(&mut game.clone(), depth);
+        unmake_time = unmake_time.min(start.elapsed().as_secs_f64());
+        assert_eq!(nodes, unmake_nodes);
+    }
+
+    println!("{name}, perft({depth}) = {nodes}");
+    println!("  clone + make:  {:>12.0} nodes/s", nodes as f64 / clone_time);
+    println!("  make + unmake: {:>12.0} nodes/s", nodes as f64 / unmake_time);
+    println!("  speedup: {:.2}x", clone_time / unmake_time);
+}
+
+fn main() {
+    for ruleset in [Ruleset::Russian, Ruleset::English] {
+        compare(&ruleset.to_string(), Game::new(ruleset), DEPTH);
+    }
+    compare(&Ruleset::International.to_string(), InternationalGame::new(), LARGE_BOARD_DEPTH);
+    compare(&Ruleset::Turkish.to_string(), TurkishGame::new(), LARGE_BOARD_DEPTH);
+}
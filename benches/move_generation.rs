@@ -0,0 +1,79 @@
+// Compares the recursive capture generator that makes jumps in place and returns a `Vec`
+// with the iterative one filling a `MoveList`, on the same positions.
+// Run with `cargo bench --bench move_generation`.
+use checkers_cpu::game::{Game, MoveList, Ruleset};
+use std::hint::black_box;
+use std::time::Instant;
+
+const GAMES: usize = 200;
+const REPEATS: usize = 100;
+const RUNS: usize = 5;
+
+// Positions with captures from random games.
+fn collect_positions(ruleset: Ruleset) -> Vec<Game> {
+    let mut rng = fastrand::Rng::with_seed(1);
+    let mut positions = Vec::new();
+    for _ in 0..GAMES {
+        let mut game = Game::new(ruleset);
+        loop {
+            let moves = game.get_moves();
+            if moves.is_empty() || game.is_draw() {
+                break;
+            }
+            if moves[0].is_capture() {
+                positions.push(game.clone());
+            }
+            game.make_move(moves[rng.usize(..moves.len())]);
+            game.change_player();
+        }
+    }
+    positions
+}
+
+// Time of one run in seconds.
+fn measure(positions: &[Game], mut generate: impl FnMut(&Game) -> usize) -> f64 {
+    let start = Instant::now();
+    for _ in 0..REPEATS {
+        for game in positions {
+            black_box(generate(black_box(game)));
+        }
+    }
+    start.elapsed().as_secs_f64()
+}
+
+fn main() {
+    for ruleset in [Ruleset::Russian, Ruleset::English, Ruleset::Brazilian, Ruleset::Italian] {
+        let positions = collect_positions(ruleset);
+        for game in &positions {
+            assert_eq!(game.get_moves_with_takes().len(), game.get_moves_with_takes_recursive().len());
+        }
+
+        // The best of several interleaved runs, to reduce the noise.
+        let mut times = [f64::INFINITY; 3];
+        let mut list = MoveList::new();
+        for _ in 0..RUNS {
+            let results = [
+                measure(&positions, |game| game.get_moves_with_takes_recursive().len()),
+                measure(&positions, |game| game.get_moves_with_takes().len()),
+                measure(&positions, |game| {
+                    list.clear();
+                    game.generate_moves_with_takes(&mut list);
+                    list.len()
+                }),
+            ];
+            for (time, result) in times.iter_mut().zip(results) {
+                *time = time.min(result);
+            }
+        }
+
+        let calls = (positions.len() * REPEATS) as f64;
+        println!("{ruleset}, {} positions with captures", positions.len());
+        for (name, time) in ["recursive, Vec", "iterative, new MoveList", "iterative, reused MoveList"].iter().zip(times) {
+            println!(
+                "  {name:<27} {:>7.1} ns/position, speedup {:.2}x",
+                time / calls * 1e9,
+                times[0] / time
+            );
+        }
+    }
+}
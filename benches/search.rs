@@ -0,0 +1,68 @@
+// Counts the nodes searched to every depth on a fixed set of positions, with the moves tried
+// in the generation order and with the move ordering of the search.
+// Run with `cargo bench --bench search`.
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot, SearchLimits};
+use checkers_cpu::game::{Game, PositionHistory, Ruleset};
+use std::time::Instant;
+
+const POSITIONS: usize = 20;
+// Random moves played from the start to get a position.
+const OPENING_MOVES: usize = 10;
+const MAX_DEPTH: i32 = 9;
+const TABLE_SIZE_MB: usize = 16;
+
+fn collect_positions() -> Vec<Game> {
+    let mut rng = fastrand::Rng::with_seed(1);
+    let mut positions = Vec::new();
+    while positions.len() < POSITIONS {
+        let mut game = Game::new(Ruleset::Russian);
+        for _ in 0..OPENING_MOVES {
+            let moves = game.get_moves();
+            if moves.is_empty() {
+                break;
+            }
+            game.make_move(moves[rng.usize(..moves.len())]);
+            game.change_player();
+        }
+        if !game.get_moves().is_empty() {
+            positions.push(game);
+        }
+    }
+    positions
+}
+
+// Nodes searched by the iterative deepening to the depth.
+fn count_nodes(bot: &mut NegaScoutBot, game: &Game, depth: i32, use_move_ordering: bool) -> u64 {
+    {
+        let mut searcher = bot.bot.try_lock().unwrap();
+        searcher.table.clear();
+        searcher.use_move_ordering = use_move_ordering;
+    }
+    bot.limits = SearchLimits::depth(depth);
+    bot.start_search(game.clone(), PositionHistory::default(), 0);
+    while !matches!(bot.poll(), BotState::Finished(_)) {}
+    bot.stop();
+    let nodes = bot.bot.try_lock().unwrap().nodes;
+    nodes
+}
+
+fn main() {
+    let positions = collect_positions();
+    let mut bot: NegaScoutBot = NegaScoutBot::with_table_size(SearchLimits::depth(1), TABLE_SIZE_MB);
+    println!("{} positions after {OPENING_MOVES} random moves", positions.len());
+    println!("{:>5} {:>14} {:>14} {:>7}", "depth", "unordered", "ordered", "ratio");
+    for depth in 1..=MAX_DEPTH {
+        let start = Instant::now();
+        let unordered: u64 = positions.iter().map(|game| count_nodes(&mut bot, game, depth, false)).sum();
+        let unordered_time = start.elapsed().as_secs_f64();
+        let start = Instant::now();
+        let ordered: u64 = positions.iter().map(|game| count_nodes(&mut bot, game, depth, true)).sum();
+        let ordered_time = start.elapsed().as_secs_f64();
+        println!(
+            "{depth:>5} {unordered:>14} {ordered:>14} {:>7.2}   ({:.0} ms / {:.0} ms)",
+            ordered as f64 / unordered as f64,
+            unordered_time * 1e3,
+            ordered_time * 1e3,
+        );
+    }
+}
@@ -2,7 +2,9 @@ pub mod all_params;
 pub mod game_scene;
 pub mod menu_scene;
 
+use crate::app::all_params::game_params::position_params::PositionParams;
 use crate::app::all_params::AllParams;
+use crate::game::AnyGame;
 use egui_macroquad::egui;
 use egui_macroquad::egui::{Response, Slider};
 use egui_macroquad::macroquad::time::get_time;
@@ -14,7 +16,7 @@ pub fn prepare_params_for_a_new_game(params: &mut AllParams) {
     params.evaluation_bar = Default::default();
     params.game_params.players[0].recreate_bot();
     params.game_params.players[1].recreate_bot();
-    params.game_params.curr_pos_params = Default::default();
+    params.game_params.curr_pos_params = PositionParams::new(AnyGame::new(params.menu_params.ruleset));
     params.game_params.history.clear();
     params.hint_params.highlighted_cells.clear();
     params.hint_params.need_hint = false;
@@ -32,7 +32,8 @@ impl AllParams {
         self.hint_params.need_hint = false;
         self.hint_params.highlighted_cells.clear();
         self.timer.last_move_time = get_time();
-        self.evaluation_bar.bot.search_depth = 1;
+        self.evaluation_bar.bot.limits.max_depth = 1;
+        self.evaluation_bar.last_result = None;
     }
 }
 
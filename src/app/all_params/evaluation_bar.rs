@@ -1,10 +1,13 @@
-use crate::bot::NegaScoutBot;
+use crate::bot::{NegaScoutBot, SearchResult};
+use crate::game::AnyGame;
 
 pub struct EvaluationBar {
-    pub bot: NegaScoutBot,
+    pub bot: NegaScoutBot<AnyGame>,
     pub new_evaluation: f32,
     pub displayed_evaluation: f32,
     pub last_evaluated_move: i32,
+    // The deepest search of the current position, shown in the menu.
+    pub last_result: Option<SearchResult>,
 }
 
 impl Default for EvaluationBar {
@@ -14,6 +17,7 @@ impl Default for EvaluationBar {
             new_evaluation: 0.0,
             displayed_evaluation: 0.0,
             last_evaluated_move: 0,
+            last_result: None,
         }
     }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::game::Position;
 use position_params::PositionParams;
 use player::Player;
 
@@ -14,11 +15,11 @@ pub struct GameParams {
 impl GameParams {
     #[inline(always)]
     pub fn get_curr_player(&self) -> &Player {
-        &self.players[!self.curr_pos_params.game.current_player as usize]
+        &self.players[!self.curr_pos_params.game.current_player() as usize]
     }
 
     #[inline(always)]
     pub fn get_curr_player_mut(&mut self) -> &mut Player {
-        &mut self.players[!self.curr_pos_params.game.current_player as usize]
+        &mut self.players[!self.curr_pos_params.game.current_player() as usize]
     }
 }
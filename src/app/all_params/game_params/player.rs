@@ -1,8 +1,9 @@
 use crate::bot::Bot;
+use crate::game::AnyGame;
 
 pub enum Player {
     Human,
-    Computer(Box<dyn Bot>),
+    Computer(Box<dyn Bot<AnyGame>>),
 }
 
 impl Player {
@@ -13,10 +14,11 @@ impl Player {
         }
     }
 
-    pub fn get_computer_mut(&mut self) -> &mut Box<dyn Bot> {
+    // `None` for a human player.
+    pub fn get_computer_mut(&mut self) -> Option<&mut Box<dyn Bot<AnyGame>>> {
         match self {
-            Player::Human => panic!("Should be Computer"),
-            Player::Computer(computer) => computer
+            Player::Human => None,
+            Player::Computer(computer) => Some(computer),
         }
     }
 }
@@ -1,48 +1,54 @@
 use rustc_hash::FxHashSet;
-use crate::game::{Game, Winner};
+use crate::game::{AnyGame, Move, Position, PositionHistory, Winner};
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct PositionParams {
-    pub game: Game,
-    pub last_correct_game_state: Game,
+    pub game: AnyGame,
+    pub last_correct_game_state: AnyGame,
+    pub position_history: PositionHistory<AnyGame>,
     pub selected_checker: Option<i8>,
+    // Cells of the unfinished capture made by the player.
+    pub capture_path: Vec<i8>,
     pub full_current_move: Vec<i8>,
     pub full_current_move_hash_set: FxHashSet<i8>,
+    // Cells of the checkers captured by the last move.
+    pub captured_cells: FxHashSet<i8>,
     pub next_possible_cells: FxHashSet<i8>,
     pub selected_move_with_capture: bool,
     pub winner: Option<Winner>,
     pub moves_cnt: i32,
 }
 
-impl Default for PositionParams {
-    fn default() -> Self {
-        Self {
-            game: Game::default(),
-            last_correct_game_state: Game::default(),
-            selected_checker: None,
-            full_current_move: vec![],
-            full_current_move_hash_set: Default::default(),
-            next_possible_cells: Default::default(),
-            selected_move_with_capture: false,
-            winner: None,
-            moves_cnt: 0,
+impl PositionParams {
+    pub fn new(game: AnyGame) -> PositionParams {
+        PositionParams {
+            last_correct_game_state: game.clone(),
+            game,
+            ..Default::default()
         }
     }
-}
 
-impl PositionParams {
+    // Shows the finished move on the board.
+    pub(crate) fn set_last_move(&mut self, last_move: &Move) {
+        self.full_current_move = last_move.as_vec();
+        self.captured_cells = FxHashSet::from_iter(last_move.captured().iter().copied());
+        self.update_current_move_hash_set();
+    }
+
     pub(crate) fn update_current_move_hash_set(&mut self) {
         self.full_current_move_hash_set =
-            FxHashSet::from_iter(self.full_current_move.clone().into_iter());
+            FxHashSet::from_iter(self.full_current_move.clone());
     }
 
     #[inline(always)]
     pub fn complete_full_move(&mut self) {
         self.selected_checker = None;
+        self.capture_path.clear();
         self.game.change_player();
+        self.position_history.push(&self.last_correct_game_state);
         self.last_correct_game_state = self.game.clone();
         self.moves_cnt += 1;
-        self.winner = self.game.get_winner();
+        self.winner = self.game.get_winner(&self.position_history);
     }
 }
 
@@ -1,15 +1,11 @@
 use player_settings::PlayerSettings;
+use crate::game::Ruleset;
 
 pub mod player_settings;
 
+#[derive(Default)]
 pub struct MenuParams {
     pub player_settings: [PlayerSettings; 2],
+    pub ruleset: Ruleset,
 }
 
-impl Default for MenuParams {
-    fn default() -> Self {
-        MenuParams {
-            player_settings: Default::default(),
-        }
-    }
-}
\ No newline at end of file
@@ -1,17 +1,29 @@
 use crate::app::all_params::game_params::player::Player;
 use crate::app::all_params::menu_params::player_settings::player_creator::PlayerCreator;
+use crate::bot::SearchLimits;
 
 pub mod player_creator;
 
 pub struct PlayerSettings {
     pub player_creator: PlayerCreator,
     pub nega_scout_search_depth: i32,
+    // The computer thinks for `nega_scout_seconds_per_move` instead of searching to a fixed depth.
+    pub nega_scout_use_time_limit: bool,
+    pub nega_scout_seconds_per_move: f64,
 }
 
 impl PlayerSettings {
     pub fn create_player(&self) -> Player {
         (self.player_creator.func)(self)
     }
+
+    pub fn search_limits(&self) -> SearchLimits {
+        if self.nega_scout_use_time_limit {
+            SearchLimits::time(self.nega_scout_seconds_per_move)
+        } else {
+            SearchLimits::depth(self.nega_scout_search_depth)
+        }
+    }
 }
 
 impl Default for PlayerSettings {
@@ -19,6 +31,8 @@ impl Default for PlayerSettings {
     fn default() -> Self {
         PlayerSettings {
             nega_scout_search_depth: 10,
+            nega_scout_use_time_limit: false,
+            nega_scout_seconds_per_move: 1.0,
             player_creator: Default::default(),
         }
     }
@@ -13,6 +13,7 @@ pub struct UiParams {
     pub board_black_color: Color,
     pub highlight_color: Color,
     pub hint_color: Color,
+    pub captured_color: Color,
     pub eval_bar_white: Color,
     pub eval_bar_black: Color,
     pub eval_bar_gray: Color,
@@ -51,6 +52,7 @@ impl Default for UiParams {
             eval_bar_black: color_u8!(50, 48, 49, 255),
             eval_bar_gray: color_u8!(150, 150, 160, 255),
             hint_color: color_u8!(255, 201, 14, 100),
+            captured_color: color_u8!(200, 40, 40, 80),
             font: load_ttf_font_from_bytes(FONT).expect("Unable to load font"),
         }
     }
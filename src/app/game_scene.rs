@@ -5,8 +5,7 @@ use crate::app::all_params::AllParams;
 use crate::app::{create_delay_between_moves_label, create_delay_between_moves_slider, create_target_fps_label, create_target_fps_slider, create_ui_scale_label, create_ui_scale_slider, prepare_params_for_a_new_game};
 use crate::bot::{Bot, BotState, SearchResult};
 use crate::constants::CONSTANT_UI_SCALE_COEFFICIENT;
-use crate::game::{Checker, Move, Winner};
-use crate::useful_functions::conv_2d_to_1d;
+use crate::game::{Checker, Move, Position, Winner};
 use egui::Button;
 use egui_macroquad::egui;
 use egui_macroquad::egui::epaint::ahash::HashSetExt;
@@ -16,32 +15,36 @@ use rustc_hash::FxHashSet;
 use std::default::Default;
 
 fn make_bot_move(params: &mut AllParams, search_result: SearchResult) {
+    // The bot is only asked to move while the game isn't over.
+    let Some(best_move) = search_result.best_move else {
+        return;
+    };
     if params.timer.time_since_last_move() >= params.timer.delay_between_moves {
         let position_params = &mut params.game_params.curr_pos_params;
-        position_params.full_current_move = search_result.best_move.clone().into();
-        position_params.game.make_move(search_result.best_move.clone());
+        position_params.set_last_move(&best_move);
+        position_params.game.make_move(best_move);
         params.complete_full_move();
-        let position_params = &mut params.game_params.curr_pos_params;
-        position_params.update_current_move_hash_set();
     }
 }
 
 fn poll_bot_and_try_make_move(params: &mut AllParams) {
-    match params.game_params.get_curr_player_mut().get_computer_mut().poll() {
+    let Some(bot) = params.game_params.get_curr_player_mut().get_computer_mut() else {
+        return;
+    };
+    match bot.poll() {
         BotState::Pending(_) => {}
         BotState::Finished(search_result) => {
             let search_result = search_result.clone();
-            params.game_params.get_curr_player_mut().get_computer_mut().stop();
+            bot.stop();
             make_bot_move(params, search_result);
         }
         BotState::NotStarted => {
             let game = params.game_params.curr_pos_params.game.clone();
+            let history = params.game_params.curr_pos_params.position_history.clone();
             let moves_cnt = params.game_params.curr_pos_params.moves_cnt;
-            params
-                .game_params
-                .get_curr_player_mut()
-                .get_computer_mut()
-                .start_search(game, moves_cnt);
+            if let Some(bot) = params.game_params.get_curr_player_mut().get_computer_mut() {
+                bot.start_search(game, history, moves_cnt);
+            }
         }
     }
 }
@@ -54,7 +57,7 @@ fn update_evaluation_bar(params: &mut AllParams) {
         params.evaluation_bar.new_evaluation = match winner {
             Winner::White => f32::INFINITY,
             Winner::Black => f32::NEG_INFINITY,
-            Winner::Draw => 0.0,
+            Winner::Draw(_) => 0.0,
         };
         return;
     }
@@ -67,17 +70,19 @@ fn update_evaluation_bar(params: &mut AllParams) {
         BotState::Finished(search_result) => {
             // let eval = params.game_params.curr_pos_params.game.evaluate_for_me();
             let search_result = search_result.clone();
-            let player_coeff = params.game_params.curr_pos_params.game.current_player as i32 * 2 - 1;
-            params.hint_params.highlighted_cells = FxHashSet::from_iter(search_result.best_move.as_vec().into_iter());
+            let player_coeff = params.game_params.curr_pos_params.game.current_player() as i32 * 2 - 1;
+            params.hint_params.highlighted_cells = FxHashSet::from_iter(search_result.best_move.iter().flat_map(Move::as_vec));
             let bar = &mut params.evaluation_bar;
             bar.new_evaluation = (search_result.game_evaluation * player_coeff) as f32 / 1000.0;
             // bar.new_evaluation = (eval * player_coeff) as f32 / 1000.0;
-            bar.bot.search_depth += 1;
+            bar.last_result = Some(search_result);
+            bar.bot.limits.max_depth += 1;
             bar.bot.stop();
         }
         BotState::NotStarted => {
             params.evaluation_bar.bot.start_search(
                 params.game_params.curr_pos_params.last_correct_game_state.clone(),
+                params.game_params.curr_pos_params.position_history.clone(),
                 params.game_params.curr_pos_params.moves_cnt,
             );
         }
@@ -93,58 +98,65 @@ fn process_click_on_board(pos_params: &mut PositionParams, history: &mut Vec<Pos
         let to = clicked_cell;
         pos_params.next_possible_cells.clear();
         if !pos_params.selected_move_with_capture {
-            pos_params.game.make_move(Move::Simple(from, to));
-            pos_params.full_current_move = vec![from, to];
-            pos_params.update_current_move_hash_set();
+            let Some(m) = pos_params.game.find_move(&[from, to]) else {
+                return false;
+            };
+            pos_params.game.make_move(m);
+            pos_params.set_last_move(&m);
             return true;
         } else {
-            if let Some(to) = pos_params.full_current_move.last() {
-                if !pos_params.game.is_empty_cell(*to) && pos_params.game.is_white_checker(*to) != pos_params.game.current_player {
-                    pos_params.full_current_move.clear();
-                }
+            if pos_params.capture_path.is_empty() {
+                pos_params.capture_path.push(from);
             }
-            if pos_params.full_current_move.is_empty() {
-                pos_params.full_current_move.push(from);
+            pos_params.capture_path.push(to);
+            pos_params.full_current_move = pos_params.capture_path.clone();
+            pos_params.captured_cells.clear();
+            if pos_params.game.make_capture_step(from, to).is_err() {
+                // Only offered jumps are clicked, but start the move again rather than show a broken position.
+                pos_params.game = pos_params.last_correct_game_state.clone();
+                pos_params.capture_path.clear();
+                pos_params.full_current_move.clear();
+                return false;
             }
-            pos_params.full_current_move.push(to);
-            pos_params.game.make_move(Move::Take(vec![from, to]));
-            let new_cuts = pos_params.game.get_cuts_from_cell(to);
-            if new_cuts.is_empty() {
-                pos_params.update_current_move_hash_set();
+            // Captures starting with the cells chosen so far.
+            let path = &pos_params.capture_path;
+            let captures: Vec<Move> = pos_params
+                .last_correct_game_state
+                .get_moves()
+                .into_iter()
+                .filter(|m| m.path().starts_with(path))
+                .collect();
+            if let Some(&m) = captures.iter().find(|m| m.path().len() == path.len()) {
+                pos_params.game = pos_params.last_correct_game_state.clone();
+                pos_params.game.make_move(m);
+                pos_params.set_last_move(&m);
                 return true;
             } else {
                 pos_params.selected_checker = Some(to);
-                for m in new_cuts {
-                    let second_cell = match m {
-                        Move::Take(v) => v[1],
-                        _ => unreachable!(),
-                    };
-                    pos_params.next_possible_cells.insert(second_cell);
+                for m in captures {
+                    pos_params.next_possible_cells.insert(m.path()[path.len()]);
                 }
             }
         }
     } else {
-        // Selecting move
+        // Selecting move. An unfinished capture is cancelled.
+        if !pos_params.capture_path.is_empty() {
+            pos_params.capture_path.clear();
+            pos_params.full_current_move.clear();
+            pos_params.game = pos_params.last_correct_game_state.clone();
+        }
         let from = clicked_cell;
         let moves: Vec<Move> = pos_params
             .game
             .get_moves()
             .into_iter()
-            .filter(|m| match &m {
-                Move::Simple(a, _) => *a == from,
-                Move::Take(v) => v[0] == from,
-            })
+            .filter(|m| m.from() == from)
             .collect();
         pos_params.next_possible_cells = FxHashSet::with_capacity(moves.len());
         for m in &moves {
-            let second_cell = m.as_vec()[1];
-            pos_params.next_possible_cells.insert(second_cell);
-        }
-        if let Some(Move::Take(_)) = &moves.first() {
-            pos_params.selected_move_with_capture = true;
-        } else {
-            pos_params.selected_move_with_capture = false;
+            pos_params.next_possible_cells.insert(m.path()[1]);
         }
+        pos_params.selected_move_with_capture = moves.first().is_some_and(Move::is_capture);
         pos_params.selected_checker = Some(from);
     }
     pos_params.update_current_move_hash_set();
@@ -159,7 +171,8 @@ pub async fn draw_game_frame(params: &mut AllParams) {
     let y_offset = min_res * 0.03;
     let board_width = min_res - y_offset;
     let x_offset = (width - board_width) / 2.0;
-    let cell_size = board_width / 8.0;
+    let board_size = params.game_params.curr_pos_params.game.board_size();
+    let cell_size = board_width / board_size as f32;
     let texture_draw_offset = cell_size * 0.02;
     let hint_circle_radius = cell_size / 2.0 * 0.4;
     let board_letters_offset = min_res * 0.006;
@@ -174,8 +187,8 @@ pub async fn draw_game_frame(params: &mut AllParams) {
         if mouse_x >= x_offset && mouse_y >= y_offset {
             let x = ((mouse_x - x_offset) / cell_size) as usize;
             let y = ((mouse_y - y_offset) / cell_size) as usize;
-            if x < 8 && y < 8 {
-                let clicked_cell: i8 = conv_2d_to_1d(x, y);
+            if x < board_size && y < board_size {
+                let clicked_cell: i8 = params.game_params.curr_pos_params.game.cell_index(x, y);
                 if process_click_on_board(&mut params.game_params.curr_pos_params, &mut params.game_params.history, clicked_cell) {
                     params.complete_full_move();
                 }
@@ -205,22 +218,26 @@ pub async fn draw_game_frame(params: &mut AllParams) {
                     prepare_params_for_a_new_game(params);
                     return;
                 }
-                if !params.game_params.history.is_empty() {
-                    if ui.add_sized(size, Button::new("Back ⬅")).clicked() {
-                        params.complete_full_move();
-                        params.game_params.curr_pos_params = params.game_params.history.pop().unwrap();
-                    }
+                if !params.game_params.history.is_empty() && ui.add_sized(size, Button::new("Back ⬅")).clicked() {
+                    params.complete_full_move();
+                    params.game_params.curr_pos_params = params.game_params.history.pop().unwrap();
                 }
                 ui.add_sized((size.x, size.y / 4.0), egui::Separator::default());
                 if ui.add_sized(size, Button::new("Hint 💡")).clicked() {
                     params.hint_params.need_hint = !params.hint_params.need_hint;
                 }
+                if let Some(result) = &params.evaluation_bar.last_result {
+                    ui.label(format!("Depth {}, {:.0}k nodes/s", result.depth, result.nodes_per_second() / 1000.0));
+                    let game = &params.game_params.curr_pos_params.game;
+                    let pv: Vec<String> = result.pv.iter().map(|m| game.move_notation(m)).collect();
+                    ui.label(pv.join(" "));
+                }
             });
         if let Some(winner) = params.game_params.curr_pos_params.winner {
             let game_time = params.timer.time_until_last_move();
             let text = format!(
                 "{}\nTime spent: {:.2}s\nAvg move: {:.4}s",
-                winner.to_string(),
+                winner,
                 game_time,
                 game_time / params.game_params.curr_pos_params.moves_cnt as f64,
             );
@@ -239,7 +256,6 @@ pub async fn draw_game_frame(params: &mut AllParams) {
                                 bot.stop();
                             }
                         }
-                        return;
                     }
                 });
         }
@@ -281,21 +297,31 @@ pub async fn draw_game_frame(params: &mut AllParams) {
         },
     );
     // Board
-    for i in 0..64 {
-        let x = i % 8;
-        let y = i / 8;
+    let all_cells_playable = {
+        let game = &params.game_params.curr_pos_params.game;
+        game.is_playable_cell(0, 0) && game.is_playable_cell(1, 0)
+    };
+    for i in 0..board_size * board_size {
+        let x = i % board_size;
+        let y = i / board_size;
         let real_x1 = x as f32 * cell_size + x_offset;
         let real_y1 = y as f32 * cell_size + y_offset;
-        let (color1, color2) = if (x + y) % 2 == 0 {
-            (params.ui_params.board_white_color, params.ui_params.board_black_color)
+        // Boards using every cell are still drawn checkered to keep the cells distinguishable.
+        let is_dark = if all_cells_playable {
+            (x + y) % 2 == 1
         } else {
+            params.game_params.curr_pos_params.game.is_playable_cell(x, y)
+        };
+        let (color1, color2) = if is_dark {
             (params.ui_params.board_black_color, params.ui_params.board_white_color)
+        } else {
+            (params.ui_params.board_white_color, params.ui_params.board_black_color)
         };
         draw_rectangle(real_x1, real_y1, cell_size, cell_size, color1);
         let font_size = min_res * 0.02;
         if x == 0 {
             draw_text_ex(
-                &(8 - y).to_string(),
+                &(board_size - y).to_string(),
                 real_x1 + board_letters_offset,
                 real_y1 + font_size + board_letters_offset,
                 TextParams {
@@ -306,9 +332,9 @@ pub async fn draw_game_frame(params: &mut AllParams) {
                 },
             );
         }
-        if y == 7 {
+        if y == board_size - 1 {
             draw_text_ex(
-                &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'][x].to_string(),
+                &((b'a' + x as u8) as char).to_string(),
                 real_x1 + cell_size - font_size - board_letters_offset,
                 real_y1 + cell_size - board_letters_offset,
                 TextParams {
@@ -319,10 +345,13 @@ pub async fn draw_game_frame(params: &mut AllParams) {
                 },
             );
         }
-        let as_flat = 63 - i as i8;
+        let as_flat = params.game_params.curr_pos_params.game.cell_index(x, y);
         if params.game_params.curr_pos_params.full_current_move_hash_set.contains(&as_flat) {
             draw_rectangle(real_x1, real_y1, cell_size, cell_size, params.ui_params.highlight_color);
         }
+        if params.game_params.curr_pos_params.captured_cells.contains(&as_flat) {
+            draw_rectangle(real_x1, real_y1, cell_size, cell_size, params.ui_params.captured_color);
+        }
         if params.hint_params.need_hint && params.game_params.curr_pos_params.winner.is_none() && params.hint_params.highlighted_cells.contains(&as_flat)
         {
             draw_rectangle(real_x1, real_y1, cell_size, cell_size, params.ui_params.hint_color);
@@ -342,9 +371,9 @@ pub async fn draw_game_frame(params: &mut AllParams) {
         }
     }
     let game_data = params.game_params.curr_pos_params.game.get_data();
-    for i in 0..8 {
-        for j in 0..8 {
-            let texture = match game_data[i][j].clone() {
+    for (i, row) in game_data.iter().enumerate() {
+        for (j, checker) in row.iter().enumerate() {
+            let texture = match checker {
                 Checker::Empty => continue,
                 Checker::White => params.ui_params.white_texture,
                 Checker::Black => params.ui_params.black_texture,
@@ -9,6 +9,7 @@ use crate::app::{create_delay_between_moves_label, create_delay_between_moves_sl
 use crate::bot::NegaScoutBot;
 use crate::constants::CONSTANT_UI_SCALE_COEFFICIENT;
 use crate::app::all_params::scene::Scene;
+use crate::game::Ruleset;
 
 fn create_player_settings_ui(ui: &mut egui::Ui, player_settings: &mut PlayerSettings, label: &str) {
     ui.horizontal(|ui| {
@@ -22,8 +23,8 @@ fn create_player_settings_ui(ui: &mut egui::Ui, player_settings: &mut PlayerSett
             &mut player_settings.player_creator,
             PlayerCreator::new(
                 |settings| {
-                    Player::Computer(Box::new(NegaScoutBot::new(
-                        settings.nega_scout_search_depth,
+                    Player::Computer(Box::new(NegaScoutBot::with_limits(
+                        settings.search_limits(),
                     )))
                 },
                 1,
@@ -31,20 +32,38 @@ fn create_player_settings_ui(ui: &mut egui::Ui, player_settings: &mut PlayerSett
             "Computer",
         );
     });
-    match player_settings.player_creator.bot_type_id {
-        1 => {
-            ui.horizontal(|ui| {
+    if player_settings.player_creator.bot_type_id == 1 {
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut player_settings.nega_scout_use_time_limit, false, "Search depth");
+            ui.radio_value(&mut player_settings.nega_scout_use_time_limit, true, "Seconds per move");
+        });
+        ui.horizontal(|ui| {
+            if player_settings.nega_scout_use_time_limit {
+                ui.label("Seconds per move");
+                ui.add(Slider::new(
+                    &mut player_settings.nega_scout_seconds_per_move,
+                    0.1..=30.0,
+                ).logarithmic(true));
+            } else {
                 ui.label("Search depth");
                 ui.add(Slider::new(
                     &mut player_settings.nega_scout_search_depth,
                     1..=15,
                 ));
-            });
-        }
-        _ => {}
+            }
+        });
     }
 }
 
+fn create_ruleset_ui(ui: &mut egui::Ui, ruleset: &mut Ruleset) {
+    ui.horizontal(|ui| {
+        ui.label("Rules:");
+        for variant in Ruleset::ALL {
+            ui.radio_value(ruleset, variant, variant.to_string());
+        }
+    });
+}
+
 pub async fn draw_menu_frame(params: &mut AllParams) {
     prepare_params_for_a_new_game(params);
     let width = screen_width();
@@ -58,6 +77,8 @@ pub async fn draw_menu_frame(params: &mut AllParams) {
             .collapsible(false)
             .resizable(false)
             .show(egui_ctx, |ui| {
+                create_ruleset_ui(ui, &mut params.menu_params.ruleset);
+                ui.add(Separator::default().spacing(1.0).shrink(5.0));
                 create_player_settings_ui(
                     ui,
                     &mut params.menu_params.player_settings[0],
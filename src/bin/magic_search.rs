@@ -0,0 +1,88 @@
+// Searches magic numbers with the smallest indices for the lookup tables of the flying kings
+// and prints them in the form of `MAGIC_NUMBERS` and `MAGIC_INDEX_BITS`.
+// Usage: `cargo run --release --bin magic_search -- [seed] [tries]`, where `tries` is the number
+// of random numbers tried for every index size (1000000 by default), or `--check` to check the current numbers.
+use checkers_cpu::game::magic_numbers::{MagicChecker, MAGIC_INDEX_BITS, MAGIC_NUMBERS};
+use checkers_cpu::useful_functions::conv_1d_to_2d;
+use std::process::exit;
+
+fn usage() -> ! {
+    eprintln!("Usage: magic_search [seed] [tries] [--check]");
+    exit(2);
+}
+
+// Cells the kings can stand on.
+fn is_dark_cell(i: i8) -> bool {
+    let (x, y) = conv_1d_to_2d(i);
+    (x + y) % 2 == 1
+}
+
+fn main() {
+    let mut numbers = Vec::new();
+    let mut check = false;
+    for arg in std::env::args().skip(1) {
+        if arg == "--check" {
+            check = true;
+        } else if let Ok(value) = arg.parse::<u64>() {
+            numbers.push(value);
+        } else {
+            usage();
+        }
+    }
+    let (seed, tries) = match numbers[..] {
+        [] => (0, 1_000_000),
+        [seed] => (seed, 1_000_000),
+        [seed, tries] => (seed, tries),
+        _ => usage(),
+    };
+
+    if check {
+        let invalid: Vec<i8> = (0..64)
+            .filter(|&i| {
+                is_dark_cell(i) && !MagicChecker::new(i).is_valid(MAGIC_NUMBERS[i as usize], MAGIC_INDEX_BITS[i as usize])
+            })
+            .collect();
+        if invalid.is_empty() {
+            println!("All magic numbers are valid");
+        } else {
+            println!("Invalid magic numbers of the cells {invalid:?}");
+            exit(1);
+        }
+        return;
+    }
+
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let mut magic_numbers = [0; 64];
+    let mut index_bits = [0; 64];
+    for i in 0..64 {
+        if !is_dark_cell(i) {
+            continue;
+        }
+        let mut checker = MagicChecker::new(i);
+        let mut bits = checker.min_index_bits();
+        let magic_number = 'search: loop {
+            for _ in 0..tries {
+                // Numbers with few set bits work better.
+                let magic_number = rng.u64(..) & rng.u64(..) & rng.u64(..);
+                if checker.is_valid(magic_number, bits) {
+                    break 'search magic_number;
+                }
+            }
+            bits += 1;
+        };
+        eprintln!("cell {i}: {bits} bits");
+        magic_numbers[i as usize] = magic_number;
+        index_bits[i as usize] = bits;
+    }
+    println!("pub const MAGIC_NUMBERS: [u64; 64] = [");
+    for magic_number in magic_numbers {
+        println!("    {magic_number},");
+    }
+    println!("];");
+    println!();
+    println!("pub const MAGIC_INDEX_BITS: [u32; 64] = [");
+    for row in index_bits.chunks(8) {
+        println!("    {},", row.iter().map(u32::to_string).collect::<Vec<_>>().join(", "));
+    }
+    println!("];");
+}
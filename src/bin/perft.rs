@@ -0,0 +1,56 @@
+// Counts the move sequences from the start position, to check the move generator.
+// Usage: `cargo run --release --bin perft -- <depth> [ruleset] [--divide]`,
+// where the ruleset is `russian` (default), `english`, `brazilian`, `italian` or `giveaway`.
+// With `--divide` the count for every root move is printed too.
+use checkers_cpu::game::{Game, Ruleset};
+use std::process::exit;
+use std::time::Instant;
+
+fn parse_ruleset(name: &str) -> Option<Ruleset> {
+    Some(match name {
+        "russian" => Ruleset::Russian,
+        "english" => Ruleset::English,
+        "brazilian" => Ruleset::Brazilian,
+        "italian" => Ruleset::Italian,
+        "giveaway" => Ruleset::Giveaway,
+        _ => return None,
+    })
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: perft <depth> [russian|english|brazilian|italian|giveaway] [--divide]");
+    exit(2);
+}
+
+fn main() {
+    let mut depth = None;
+    let mut ruleset = Ruleset::Russian;
+    let mut divide = false;
+    for arg in std::env::args().skip(1) {
+        if arg == "--divide" {
+            divide = true;
+        } else if let Ok(value) = arg.parse() {
+            depth = Some(value);
+        } else if let Some(value) = parse_ruleset(&arg) {
+            ruleset = value;
+        } else {
+            usage();
+        }
+    }
+    let Some(depth) = depth else { usage() };
+
+    let mut game = Game::new(ruleset);
+    let start = Instant::now();
+    let nodes = if divide {
+        let mut nodes = 0;
+        for (m, count) in game.perft_divide(depth) {
+            println!("{m} {count}");
+            nodes += count;
+        }
+        nodes
+    } else {
+        game.perft(depth)
+    };
+    let seconds = start.elapsed().as_secs_f64();
+    println!("{ruleset}, depth {depth}: {nodes} nodes in {seconds:.3} s ({:.0} nodes/s)", nodes as f64 / seconds);
+}
@@ -1,29 +1,103 @@
+pub mod move_ordering;
 pub mod nega_scout_bot;
+pub mod transposition_table;
 
+pub use move_ordering::MoveOrdering;
 pub use nega_scout_bot::NegaScoutBot;
-use crate::game::{Game, Move};
+pub use transposition_table::{TableEntry, TranspositionTable, ValType};
+use crate::constants::MAX_SEARCH_DEPTH;
+use crate::game::{Game, Move, Position, PositionHistory};
 use genawaiter::sync::GenBoxed;
+use std::fmt::{Display, Formatter};
 
 #[derive(Clone)]
 pub struct SearchResult {
-    pub best_move: Move,
+    // `None` if the player has no moves, the evaluation is a loss then.
+    pub best_move: Option<Move>,
     pub game_evaluation: i32,
+    // Depth of the last finished iteration, the result comes from it.
+    pub depth: i32,
+    // The line both players are expected to play, starting with `best_move`. Can be shorter than the depth
+    // when the end of the line comes from the transposition table.
+    pub pv: Vec<Move>,
+    // Nodes searched by all iterations, including the unfinished one.
+    pub nodes: u64,
+    // In seconds.
+    pub time: f64,
+    // Share of the transposition table lookups that found the position.
+    pub table_hit_rate: f64,
 }
 
+impl SearchResult {
+    pub fn nodes_per_second(&self) -> f64 {
+        if self.time > 0.0 {
+            self.nodes as f64 / self.time
+        } else {
+            0.0
+        }
+    }
+}
+
+// Statistics of the search, e.g. `depth 7, 123456 nodes in 0.25 s (493827 nodes/s), table hits 35.2%`.
+impl Display for SearchResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "depth {}, {} nodes in {:.2} s ({:.0} nodes/s), table hits {:.1}%",
+            self.depth,
+            self.nodes,
+            self.time,
+            self.nodes_per_second(),
+            self.table_hit_rate * 100.0,
+        )
+    }
+}
+
+// When an iterative deepening search stops. The first iteration is always finished, so there is a move to play.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SearchLimits {
+    pub max_depth: i32,
+    // In seconds.
+    pub max_time: Option<f64>,
+    pub max_nodes: Option<u64>,
+}
+
+impl SearchLimits {
+    pub fn depth(max_depth: i32) -> SearchLimits {
+        SearchLimits {
+            max_depth,
+            max_time: None,
+            max_nodes: None,
+        }
+    }
+
+    pub fn time(seconds: f64) -> SearchLimits {
+        SearchLimits {
+            max_depth: MAX_SEARCH_DEPTH,
+            max_time: Some(seconds),
+            max_nodes: None,
+        }
+    }
+
+    pub fn nodes(max_nodes: u64) -> SearchLimits {
+        SearchLimits {
+            max_depth: MAX_SEARCH_DEPTH,
+            max_time: None,
+            max_nodes: Some(max_nodes),
+        }
+    }
+}
+
+#[derive(Default)]
 pub enum BotState {
+    #[default]
     NotStarted,
     Pending(GenBoxed<(), (), SearchResult>),
     Finished(SearchResult)
 }
 
-impl Default for BotState {
-    fn default() -> Self {
-        BotState::NotStarted
-    }
-}
-
-pub trait Bot {
-    fn start_search(&mut self, game: Game, game_move: i32);
+pub trait Bot<G: Position = Game> {
+    fn start_search(&mut self, game: G, history: PositionHistory<G>, game_move: i32);
 
     fn poll(&mut self) -> &BotState;
 
@@ -0,0 +1,90 @@
+use crate::constants::{HISTORY_MAX_SCORE, HISTORY_TABLE_CELLS, MAX_MOVES, MAX_PLY};
+use crate::game::Move;
+use std::cmp::Reverse;
+
+const HASH_MOVE_SCORE: i32 = i32::MAX;
+const CAPTURE_SCORE: i32 = 1 << 30;
+const KILLER_SCORE: i32 = 1 << 29;
+
+// Chooses the order of trying moves in the search: the best move from the transposition table,
+// then captures, then the killer moves of the ply, then quiet moves by the history heuristic.
+pub struct MoveOrdering {
+    // Two last quiet moves that caused a beta cutoff at every ply.
+    killers: Vec<[Option<Move>; 2]>,
+    // Cutoff scores of quiet moves by their start and end cells.
+    history: Vec<i32>,
+}
+
+impl MoveOrdering {
+    pub fn new() -> MoveOrdering {
+        MoveOrdering {
+            killers: vec![[None; 2]; MAX_PLY],
+            history: vec![0; HISTORY_TABLE_CELLS * HISTORY_TABLE_CELLS],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.killers.fill([None; 2]);
+        self.history.fill(0);
+    }
+
+    #[inline(always)]
+    fn history_index(m: &Move) -> usize {
+        m.from() as usize * HISTORY_TABLE_CELLS + m.to() as usize
+    }
+
+    fn score(&self, m: &Move, i: usize, hash_move: Option<usize>, ply: usize) -> i32 {
+        if hash_move == Some(i) {
+            return HASH_MOVE_SCORE;
+        }
+        if m.is_capture() {
+            return CAPTURE_SCORE + m.captured().len() as i32;
+        }
+        if let Some(killers) = self.killers.get(ply) {
+            if killers[0] == Some(*m) {
+                return KILLER_SCORE + 1;
+            }
+            if killers[1] == Some(*m) {
+                return KILLER_SCORE;
+            }
+        }
+        self.history[Self::history_index(m)]
+    }
+
+    // Indices of the moves from the first one to try to the last one. The first `moves.len()` are used.
+    pub fn order(&self, moves: &[Move], hash_move: Option<usize>, ply: usize) -> [u8; MAX_MOVES] {
+        let mut scores = [(0, 0); MAX_MOVES];
+        for (i, m) in moves.iter().enumerate() {
+            scores[i] = (self.score(m, i, hash_move, ply), i as u8);
+        }
+        scores[..moves.len()].sort_unstable_by_key(|&(score, i)| (Reverse(score), i));
+        scores.map(|(_, i)| i)
+    }
+
+    // Remembers a quiet move that caused a beta cutoff.
+    pub fn update(&mut self, m: &Move, depth: i32, ply: usize) {
+        if m.is_capture() {
+            return;
+        }
+        if let Some(killers) = self.killers.get_mut(ply) {
+            if killers[0] != Some(*m) {
+                killers[1] = killers[0];
+                killers[0] = Some(*m);
+            }
+        }
+        let index = Self::history_index(m);
+        self.history[index] += depth * depth;
+        if self.history[index] > HISTORY_MAX_SCORE {
+            // Old cutoffs count less than new ones.
+            for score in &mut self.history {
+                *score /= 2;
+            }
+        }
+    }
+}
+
+impl Default for MoveOrdering {
+    fn default() -> Self {
+        MoveOrdering::new()
+    }
+}
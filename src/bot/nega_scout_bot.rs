@@ -1,120 +1,150 @@
-use crate::bot::{Bot, BotState, SearchResult};
-use crate::constants::{MAX_NEGA_SCOUT_HASH_MAP_SIZE, HALF_OF_INFINITY, INFINITY, MIN_HASH_MAP_SAVE_SEARCH_DEPTH, MIN_NEGA_SCOUT_YIELD_DEPTH};
-use crate::game::Game;
+use crate::bot::{Bot, BotState, MoveOrdering, SearchLimits, SearchResult, TranspositionTable, ValType};
+use crate::constants::{DEFAULT_TRANSPOSITION_TABLE_SIZE_MB, HALF_OF_INFINITY, INFINITY, MIN_NEGA_SCOUT_YIELD_DEPTH, MAX_PLY, MIN_TRANSPOSITION_TABLE_SAVE_DEPTH, SEARCH_TIME_CHECK_NODES};
+use crate::game::{Game, Move, Position, PositionHistory};
 use async_recursion::async_recursion;
-use egui_macroquad::egui::epaint::ahash::HashMapExt;
+use egui_macroquad::macroquad::miniquad::date;
 use genawaiter::sync::{Co, GenBoxed};
 use genawaiter::GeneratorState;
-use rustc_hash::FxHashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-pub enum ValType {
-    Exact,
-    Beta,
-    Alpha,
-    None,
+pub struct NegaScoutSearcher<G: Position = Game> {
+    pub table: TranspositionTable,
+    pub move_ordering: MoveOrdering,
+    // Moves are searched in the generation order without it, for comparison.
+    pub use_move_ordering: bool,
+    // Positions from the beginning of the game to the currently searched one.
+    pub history: PositionHistory<G>,
+    pub limits: SearchLimits,
+    // Nodes searched since the start of `choose_best_move`.
+    pub nodes: u64,
+    pub table_lookups: u64,
+    pub table_hits: u64,
+    // Triangular table of principal variations: the best line found from every ply of the current line.
+    pv: Vec<Vec<Move>>,
+    // Time of the start of `choose_best_move` in seconds.
+    start_time: f64,
+    // A limit was reached, the unfinished iteration is thrown away.
+    stopped: bool,
+    // Limits are only checked after the first iteration, which is always finished.
+    can_stop: bool,
 }
 
-#[derive(Clone)]
-pub struct HMapGameInfo {
-    pub eval: i32,
-    pub depth: i32,
-    pub val_type: ValType,
-    pub move_n: i32,
-}
+impl<G: Position> NegaScoutSearcher<G> {
+    pub fn new() -> NegaScoutSearcher<G> {
+        NegaScoutSearcher::with_table_size(DEFAULT_TRANSPOSITION_TABLE_SIZE_MB)
+    }
 
-impl HMapGameInfo {
-    pub fn new(eval: i32, depth: i32, val_type: ValType, move_n: i32) -> HMapGameInfo {
-        HMapGameInfo {
-            eval,
-            depth,
-            val_type,
-            move_n,
+    pub fn with_table_size(table_size_mb: usize) -> NegaScoutSearcher<G> {
+        NegaScoutSearcher {
+            table: TranspositionTable::new(table_size_mb),
+            move_ordering: MoveOrdering::new(),
+            use_move_ordering: true,
+            history: Default::default(),
+            limits: SearchLimits::depth(1),
+            nodes: 0,
+            table_lookups: 0,
+            table_hits: 0,
+            pv: vec![Vec::new(); MAX_PLY + 1],
+            start_time: 0.0,
+            stopped: false,
+            can_stop: false,
         }
     }
-}
 
-pub struct NegaScoutSearcher {
-    pub hash_map: FxHashMap<Game, HMapGameInfo>,
-}
+    #[inline(always)]
+    fn elapsed_time(&self) -> f64 {
+        date::now() - self.start_time
+    }
 
-impl NegaScoutSearcher {
-    pub fn new() -> NegaScoutSearcher {
-        NegaScoutSearcher {
-            hash_map: FxHashMap::with_capacity(MAX_NEGA_SCOUT_HASH_MAP_SIZE),
+    // Sets `stopped` if the time or the nodes limit is reached.
+    fn check_limits(&mut self) {
+        if !self.can_stop {
+            return;
         }
+        let out_of_nodes = self.limits.max_nodes.is_some_and(|max_nodes| self.nodes >= max_nodes);
+        let out_of_time = self.nodes.is_multiple_of(SEARCH_TIME_CHECK_NODES)
+            && self.limits.max_time.is_some_and(|max_time| self.elapsed_time() >= max_time);
+        self.stopped |= out_of_nodes || out_of_time;
+    }
+
+    // The move followed by the principal variation of the next ply becomes the principal variation of the ply.
+    fn update_pv(&mut self, ply: usize, m: Move) {
+        if ply >= MAX_PLY {
+            return;
+        }
+        let (lines, next_lines) = self.pv.split_at_mut(ply + 1);
+        let line = &mut lines[ply];
+        line.clear();
+        line.push(m);
+        line.extend_from_slice(&next_lines[0]);
+    }
+
+    fn fill_stats(&self, result: &mut SearchResult) {
+        result.nodes = self.nodes;
+        result.time = self.elapsed_time();
+        result.table_hit_rate = if self.table_lookups > 0 {
+            self.table_hits as f64 / self.table_lookups as f64
+        } else {
+            0.0
+        };
+    }
+}
+
+impl<G: Position> Default for NegaScoutSearcher<G> {
+    fn default() -> Self {
+        NegaScoutSearcher::new()
     }
 }
 
-impl NegaScoutSearcher {
+impl<G: Position> NegaScoutSearcher<G> {
     #[async_recursion]
     async fn search(
         &mut self,
-        game: &Game,
-        game_move: i32,
+        game: &mut G,
+        ply: usize,
         depth: i32,
         mut alpha: i32,
         mut beta: i32,
         co: &Co<()>,
     ) -> i32 {
-        if game.is_draw() {
+        self.nodes += 1;
+        if let Some(line) = self.pv.get_mut(ply) {
+            line.clear();
+        }
+        self.check_limits();
+        if self.stopped {
+            return 0;
+        }
+        if self.history.get_draw_reason(game).is_some() {
             return 0;
         }
         // Checking for depth to keep good performance.
         if depth >= MIN_NEGA_SCOUT_YIELD_DEPTH {
             co.yield_(()).await;
         }
-        // Clearing the hashmap if it gets too large.
-        if self.hash_map.len() >= MAX_NEGA_SCOUT_HASH_MAP_SIZE {
-            let mut games_to_delete = Vec::new();
-            for (curr_game, info) in self.hash_map.iter() {
-                if info.move_n + info.depth < game_move + depth {
-                    games_to_delete.push(curr_game.clone());
-                }
-            }
-            for game in &games_to_delete {
-                self.hash_map.remove(game);
-            }
-            co.yield_(()).await;
-            if self.hash_map.len() >= MAX_NEGA_SCOUT_HASH_MAP_SIZE / 2 {
-                // Deleting old values from hashmap
-                for depth in MIN_HASH_MAP_SAVE_SEARCH_DEPTH.. {
-                    let mut games_to_delete = Vec::new();
-                    for (curr_game, info) in self.hash_map.iter() {
-                        if info.depth <= depth {
-                            games_to_delete.push(curr_game.clone());
-                        }
-                    }
-                    for game in &games_to_delete {
-                        self.hash_map.remove(game);
-                    }
-                    if self.hash_map.len() < MAX_NEGA_SCOUT_HASH_MAP_SIZE / 2 {
-                        break;
-                    }
-                    co.yield_(()).await;
-                }
-            }
-        }
         let old_alpha = alpha;
         let mut all_moves = game.get_moves_with_takes();
         if depth <= 0 && all_moves.is_empty() {
             return game.evaluate_for_me();
         }
-        // Trying to use a value from hashmap
-        if let Some(info) = self.hash_map.get(&game) {
-            if info.depth >= depth {
-                match info.val_type {
-                    ValType::Exact => return info.eval,
+        // Trying to use a value from the transposition table
+        let key = game.table_key();
+        let table_entry = self.table.get(key);
+        self.table_lookups += 1;
+        self.table_hits += table_entry.is_some() as u64;
+        if let Some(info) = table_entry {
+            if info.depth() >= depth {
+                match info.val_type() {
+                    ValType::Exact => return info.eval(),
                     ValType::Beta => {
-                        alpha = alpha.max(info.eval);
+                        alpha = alpha.max(info.eval());
                         if alpha >= beta {
                             return alpha;
                         }
                     }
                     ValType::Alpha => {
-                        beta = beta.min(info.eval);
+                        beta = beta.min(info.eval());
                         if alpha >= beta {
                             return beta;
                         }
@@ -128,126 +158,220 @@ impl NegaScoutSearcher {
             all_moves = game.get_moves_without_takes();
         }
         if all_moves.is_empty() {
-            return -HALF_OF_INFINITY - depth * 100_000 + game.evaluate_for_me();
+            // The sooner the game ends, the bigger the score.
+            let score = HALF_OF_INFINITY + depth * 100_000;
+            return if game.no_moves_wins() { score } else { -score } + game.evaluate_for_me();
         }
         let mut score = -INFINITY;
-        for curr_move in all_moves {
+        let mut best_move = None;
+        let order = if self.use_move_ordering {
+            let hash_move = table_entry.and_then(|info| info.best_move());
+            self.move_ordering.order(&all_moves, hash_move, ply)
+        } else {
+            std::array::from_fn(|i| i as u8)
+        };
+        self.history.push(game);
+        for &i in &order[..all_moves.len()] {
             if alpha >= beta {
                 break;
             }
-            let mut game_copy = game.clone();
-            game_copy.make_move(curr_move);
-            game_copy.change_player();
+            let curr_move = all_moves[i as usize];
+            let undo = game.make_move(curr_move);
+            game.change_player();
             let mut tmp = -self
-                .search(&game_copy, game_move + 1, depth - 1, -alpha - 1, -alpha, co)
+                .search(game, ply + 1, depth - 1, -alpha - 1, -alpha, co)
                 .await;
             if tmp > alpha && tmp < beta {
-                tmp = -self.search(&game_copy, game_move + 1, depth - 1, -beta, -tmp, co).await;
+                tmp = -self.search(game, ply + 1, depth - 1, -beta, -tmp, co).await;
+            }
+            game.unmake_move(undo);
+            if self.stopped {
+                break;
+            }
+            if tmp > alpha {
+                self.update_pv(ply, curr_move);
+            }
+            if tmp > score {
+                score = tmp;
+                best_move = Some(i as usize);
+            }
+            if tmp >= beta && self.use_move_ordering {
+                self.move_ordering.update(&curr_move, depth, ply);
             }
-            score = score.max(tmp);
             alpha = alpha.max(score);
         }
-        if depth < MIN_HASH_MAP_SAVE_SEARCH_DEPTH {
+        self.history.pop();
+        if self.stopped || depth < MIN_TRANSPOSITION_TABLE_SAVE_DEPTH {
             return score;
         }
-        let curr_val = self.hash_map.get(&game);
-        let curr_type = match curr_val {
-            None => ValType::None,
-            Some(val) => val.val_type,
+        let (curr_type, curr_depth) = match table_entry {
+            None => (ValType::None, -1),
+            Some(info) => (info.val_type(), info.depth()),
         };
-        if curr_val.is_none() || curr_val.unwrap().depth <= depth {
+        if curr_depth <= depth {
             if old_alpha < score && score < beta {
-                self.hash_map
-                    .insert(game.clone(), HMapGameInfo::new(score, depth, ValType::Exact, game_move));
+                self.table.store(key, score, depth, ValType::Exact, best_move);
             } else if score >= beta {
-                if curr_type != ValType::Exact || curr_val.unwrap().depth != depth {
-                    self.hash_map
-                        .insert(game.clone(), HMapGameInfo::new(score, depth, ValType::Beta, game_move));
+                if curr_type != ValType::Exact || curr_depth != depth {
+                    self.table.store(key, score, depth, ValType::Beta, best_move);
                 }
             } else if curr_type != ValType::Exact && curr_type != ValType::Beta {
-                self.hash_map
-                    .insert(game.clone(), HMapGameInfo::new(score, depth, ValType::Alpha, game_move));
+                // No move is better than the others after a fail low.
+                self.table.store(key, score, depth, ValType::Alpha, None);
             }
         }
         score
     }
 
-    pub async fn choose_best_move(
+    // Searches to the depth from the root, trying the best move of the previous iteration first.
+    // Returns `None` if a limit was reached before the iteration was finished.
+    async fn search_root(
         &mut self,
-        game: &Game,
-        game_move: i32,
+        game: &mut G,
+        all_moves: &[Move],
         depth: i32,
         co: &Co<()>,
-    ) -> SearchResult {
-        let all_moves = game.get_moves();
+    ) -> Option<SearchResult> {
         let mut alpha = -INFINITY;
         let beta = INFINITY;
         let mut score = -INFINITY;
         let mut best_move = None;
-        for curr_move in all_moves {
+        for &curr_move in all_moves {
             if alpha >= beta {
                 break;
             }
-            let mut game_copy = game.clone();
-            game_copy.make_move(curr_move.clone());
-            game_copy.change_player();
+            let undo = game.make_move(curr_move);
+            game.change_player();
             let mut tmp = -self
-                .search(&game_copy, game_move + 1, depth - 1, -alpha - 1, -alpha, co)
+                .search(game, 1, depth - 1, -alpha - 1, -alpha, co)
                 .await;
             if tmp > alpha && tmp < beta {
-                tmp = -self.search(&game_copy, game_move + 1, depth - 1, -beta, -tmp, co).await;
+                tmp = -self.search(game, 1, depth - 1, -beta, -tmp, co).await;
+            }
+            game.unmake_move(undo);
+            if self.stopped {
+                return None;
             }
             if tmp > score || best_move.is_none() {
                 score = tmp;
                 best_move = Some(curr_move);
+                self.update_pv(0, curr_move);
             }
             alpha = alpha.max(tmp);
         }
-        SearchResult {
-            best_move: best_move.expect("No moves from current position"),
+        let mut result = SearchResult {
+            best_move,
             game_evaluation: score,
+            depth,
+            pv: self.pv[0].clone(),
+            nodes: 0,
+            time: 0.0,
+            table_hit_rate: 0.0,
+        };
+        self.fill_stats(&mut result);
+        Some(result)
+    }
+
+    // Iterative deepening: searches to depth 1, 2 and so on until a limit is reached
+    // and returns the result of the last finished iteration.
+    pub async fn choose_best_move(
+        &mut self,
+        game: &G,
+        history: PositionHistory<G>,
+        limits: SearchLimits,
+        co: &Co<()>,
+    ) -> SearchResult {
+        self.history = history;
+        self.table.new_search();
+        self.move_ordering.clear();
+        self.limits = limits;
+        self.nodes = 0;
+        self.table_lookups = 0;
+        self.table_hits = 0;
+        self.pv[0].clear();
+        self.start_time = date::now();
+        self.stopped = false;
+        self.can_stop = false;
+        let mut all_moves: Vec<Move> = game.get_moves().into_iter().collect();
+        self.history.push(game);
+        let mut game = game.clone();
+        let mut result = SearchResult {
+            best_move: None,
+            game_evaluation: -INFINITY,
+            depth: 0,
+            pv: Vec::new(),
+            nodes: 0,
+            time: 0.0,
+            table_hit_rate: 0.0,
+        };
+        for depth in 1..=limits.max_depth.max(1) {
+            let Some(iteration) = self.search_root(&mut game, &all_moves, depth, co).await else {
+                break;
+            };
+            result = iteration;
+            // Without moves there is nothing to search deeper.
+            let Some(best_move) = result.best_move else {
+                break;
+            };
+            let i = all_moves.iter().position(|&m| m == best_move).unwrap_or(0);
+            all_moves[..=i].rotate_right(1);
+            self.can_stop = true;
+            self.check_limits();
+            if self.stopped || self.limits.max_time.is_some_and(|max_time| self.elapsed_time() >= max_time) {
+                break;
+            }
         }
+        self.history.pop();
+        self.fill_stats(&mut result);
+        result
     }
 }
 
-pub struct NegaScoutBot {
-    pub bot: Arc<Mutex<NegaScoutSearcher>>,
+pub struct NegaScoutBot<G: Position = Game> {
+    pub bot: Arc<Mutex<NegaScoutSearcher<G>>>,
     pub state: BotState,
-    pub search_depth: i32,
+    pub limits: SearchLimits,
+    pub table_size_mb: usize,
 }
 
-impl NegaScoutBot {
-    pub fn new(search_depth: i32) -> NegaScoutBot {
+impl<G: Position> NegaScoutBot<G> {
+    pub fn new(search_depth: i32) -> NegaScoutBot<G> {
+        NegaScoutBot::with_limits(SearchLimits::depth(search_depth))
+    }
+
+    pub fn with_limits(limits: SearchLimits) -> NegaScoutBot<G> {
+        NegaScoutBot::with_table_size(limits, DEFAULT_TRANSPOSITION_TABLE_SIZE_MB)
+    }
+
+    pub fn with_table_size(limits: SearchLimits, table_size_mb: usize) -> NegaScoutBot<G> {
         NegaScoutBot {
-            bot: Arc::new(Mutex::new(NegaScoutSearcher::new())),
+            bot: Arc::new(Mutex::new(NegaScoutSearcher::with_table_size(table_size_mb))),
             state: Default::default(),
-            search_depth,
+            limits,
+            table_size_mb,
         }
     }
 }
 
-impl Bot for NegaScoutBot {
+impl<G: Position> Bot<G> for NegaScoutBot<G> {
     // #[inline(always)]
-    fn start_search(&mut self, game: Game, game_move: i32) {
-        let search_depth = self.search_depth;
+    fn start_search(&mut self, game: G, history: PositionHistory<G>, _game_move: i32) {
+        let limits = self.limits;
         let bot = self.bot.clone();
         self.state = BotState::Pending(GenBoxed::new_boxed(move |co: Co<()>| async move {
             bot.lock()
                 .await
-                .choose_best_move(&game, game_move, search_depth, &co)
+                .choose_best_move(&game, history, limits, &co)
                 .await
         }));
     }
 
     fn poll(&mut self) -> &BotState {
-        match &mut self.state {
-            BotState::Pending(generator) => {
-                match generator.resume() {
-                    GeneratorState::Complete(res) => self.state = BotState::Finished(res),
-                    GeneratorState::Yielded(_) => {}
-                }
+        if let BotState::Pending(generator) = &mut self.state {
+            match generator.resume() {
+                GeneratorState::Complete(res) => self.state = BotState::Finished(res),
+                GeneratorState::Yielded(_) => {}
             }
-            _ => {}
         }
         &self.state
     }
@@ -257,14 +381,14 @@ impl Bot for NegaScoutBot {
     }
 
     fn recreate(&mut self) {
-        *self = NegaScoutBot::new(self.search_depth);
+        *self = NegaScoutBot::with_table_size(self.limits, self.table_size_mb);
     }
 }
 
-impl PartialEq for NegaScoutBot {
+impl<G: Position> PartialEq for NegaScoutBot<G> {
     fn eq(&self, _: &Self) -> bool {
         true
     }
 }
 
-impl Eq for NegaScoutBot {}
+impl<G: Position> Eq for NegaScoutBot<G> {}
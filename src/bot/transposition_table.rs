@@ -0,0 +1,178 @@
+use std::mem::size_of;
+
+// Fixed-size transposition table. Every key has a bucket of two entries: the first one keeps the deepest
+// search of the current age, the second one always takes the entries the first one refuses.
+
+// Kind of the stored evaluation.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ValType {
+    // The entry is empty.
+    None,
+    Exact,
+    // A lower bound, the search failed high.
+    Beta,
+    // An upper bound, the search failed low.
+    Alpha,
+}
+
+impl ValType {
+    #[inline(always)]
+    fn from_bits(bits: u8) -> ValType {
+        match bits & 3 {
+            1 => ValType::Exact,
+            2 => ValType::Beta,
+            3 => ValType::Alpha,
+            _ => ValType::None,
+        }
+    }
+}
+
+// Entries older than `AGE_MASK` searches look new again, it doesn't matter for the replacement.
+const AGE_MASK: u8 = 0x3f;
+const NO_MOVE: u8 = u8::MAX;
+
+// 12 bytes, all zeros is an empty entry.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TableEntry {
+    // The high half of the key, the low bits of the key choose the bucket.
+    key_check: u32,
+    eval: i32,
+    depth: u8,
+    // Index of the best move in `get_moves()` of the position, `NO_MOVE` if unknown.
+    best_move: u8,
+    // `ValType` in the low 2 bits and the age in the high 6 bits.
+    val_type_and_age: u8,
+}
+
+impl TableEntry {
+    #[inline(always)]
+    pub fn eval(&self) -> i32 {
+        self.eval
+    }
+
+    #[inline(always)]
+    pub fn depth(&self) -> i32 {
+        self.depth as i32
+    }
+
+    #[inline(always)]
+    pub fn val_type(&self) -> ValType {
+        ValType::from_bits(self.val_type_and_age)
+    }
+
+    // Index of the best move in `get_moves()`. Check it against the number of moves, keys can collide.
+    #[inline(always)]
+    pub fn best_move(&self) -> Option<usize> {
+        (self.best_move != NO_MOVE).then_some(self.best_move as usize)
+    }
+
+    #[inline(always)]
+    fn age(&self) -> u8 {
+        self.val_type_and_age >> 2
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.val_type() == ValType::None
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Bucket {
+    depth_preferred: TableEntry,
+    always_replace: TableEntry,
+}
+
+pub struct TranspositionTable {
+    buckets: Vec<Bucket>,
+    // Number of buckets minus one, it is a power of two.
+    mask: usize,
+    age: u8,
+}
+
+impl TranspositionTable {
+    // The largest table that fits into `size_mb` megabytes, at least one bucket.
+    pub fn new(size_mb: usize) -> TranspositionTable {
+        let max_buckets = (size_mb << 20) / size_of::<Bucket>();
+        let len = if max_buckets == 0 { 1 } else { 1 << max_buckets.ilog2() };
+        TranspositionTable {
+            buckets: vec![Bucket::default(); len],
+            mask: len - 1,
+            age: 0,
+        }
+    }
+
+    // Number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buckets.len() * 2
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    // Memory taken by the entries in bytes.
+    #[inline(always)]
+    pub fn size_in_bytes(&self) -> usize {
+        self.buckets.len() * size_of::<Bucket>()
+    }
+
+    pub fn clear(&mut self) {
+        self.buckets.fill(Bucket::default());
+        self.age = 0;
+    }
+
+    // Called at the start of every search. Entries of the previous searches can be replaced by any new entry.
+    #[inline(always)]
+    pub fn new_search(&mut self) {
+        self.age = (self.age + 1) & AGE_MASK;
+    }
+
+    #[inline(always)]
+    fn bucket_index(&self, key: u64) -> usize {
+        key as usize & self.mask
+    }
+
+    #[inline(always)]
+    fn key_check(key: u64) -> u32 {
+        (key >> 32) as u32
+    }
+
+    pub fn get(&self, key: u64) -> Option<TableEntry> {
+        let bucket = &self.buckets[self.bucket_index(key)];
+        let key_check = Self::key_check(key);
+        [bucket.depth_preferred, bucket.always_replace]
+            .into_iter()
+            .find(|entry| !entry.is_empty() && entry.key_check == key_check)
+    }
+
+    // `best_move` is an index in `get_moves()`. The best move of the stored entry of the same position
+    // is kept if the new one is unknown.
+    pub fn store(&mut self, key: u64, eval: i32, depth: i32, val_type: ValType, best_move: Option<usize>) {
+        let age = self.age;
+        let bucket_index = self.bucket_index(key);
+        let bucket = &mut self.buckets[bucket_index];
+        let key_check = Self::key_check(key);
+        let mut entry = TableEntry {
+            key_check,
+            eval,
+            depth: depth.clamp(0, u8::MAX as i32) as u8,
+            best_move: best_move.filter(|&i| i < NO_MOVE as usize).map_or(NO_MOVE, |i| i as u8),
+            val_type_and_age: age << 2 | val_type as u8,
+        };
+        let old = &bucket.depth_preferred;
+        let replaces_depth_preferred =
+            old.is_empty() || old.key_check == key_check || old.age() != age || old.depth <= entry.depth;
+        let slot = if replaces_depth_preferred {
+            &mut bucket.depth_preferred
+        } else {
+            &mut bucket.always_replace
+        };
+        if entry.best_move == NO_MOVE && !slot.is_empty() && slot.key_check == key_check {
+            entry.best_move = slot.best_move;
+        }
+        *slot = entry;
+    }
+}
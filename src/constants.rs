@@ -3,8 +3,6 @@ pub const WHITE_QUEEN_IMG: &[u8] = include_bytes!("../data/white_queen.png");
 pub const BLACK_CHECKER_IMG: &[u8] = include_bytes!("../data/black.png");
 pub const BLACK_QUEEN_IMG: &[u8] = include_bytes!("../data/black_queen.png");
 pub const FONT: &[u8] = include_bytes!("../data/font.ttf");
-pub const MOVES_WITHOUT_CAPTURES_COMPRESSED: &[u8] = include_bytes!("../data/moves_without_captures.bin.snappy");
-pub const MOVES_WITH_CAPTURES_COMPRESSED: &[u8] = include_bytes!("../data/moves_with_captures.bin.snappy");
 
 #[cfg(target_arch = "wasm32")]
 pub const CONSTANT_UI_SCALE_COEFFICIENT: f32 = 1.0 / 500.0;
@@ -17,6 +15,33 @@ pub const HALF_OF_INFINITY: i32 = 500_000_000;
 pub const PAWN_COST: i32 = 1000;
 pub const QUEEN_COST: i32 = 3000;
 
-pub const MAX_NEGA_SCOUT_HASH_MAP_SIZE: usize = 5_000_000;
-pub const MIN_HASH_MAP_SAVE_SEARCH_DEPTH: i32 = 4;
+// Draw rules limits in half-moves.
+pub const KING_MOVES_DRAW_LIMIT: u8 = 30;
+pub const THREE_KINGS_DRAW_LIMIT: u8 = 30;
+pub const THREE_KINGS_ON_MAIN_ROAD_DRAW_LIMIT: u8 = 10;
+pub const FOUR_AND_FIVE_PIECES_DRAW_LIMIT: u8 = 60;
+pub const SIX_AND_SEVEN_PIECES_DRAW_LIMIT: u8 = 120;
+pub const ENGLISH_KING_MOVES_DRAW_LIMIT: u8 = 80;
+pub const INTERNATIONAL_KING_MOVES_DRAW_LIMIT: u8 = 50;
+pub const INTERNATIONAL_THREE_PIECES_DRAW_LIMIT: u8 = 32;
+pub const INTERNATIONAL_TWO_PIECES_DRAW_LIMIT: u8 = 10;
+
+// A player has at most 20 checkers, so no capture sequence can be longer.
+pub const MAX_CAPTURES: usize = 20;
+// Capacity of `MoveList`, more than the number of legal moves in any real game position.
+pub const MAX_MOVES: usize = 256;
+
+// Size of the transposition table of every NegaScout bot in megabytes.
+pub const DEFAULT_TRANSPOSITION_TABLE_SIZE_MB: usize = 32;
+pub const MIN_TRANSPOSITION_TABLE_SAVE_DEPTH: i32 = 4;
 pub const MIN_NEGA_SCOUT_YIELD_DEPTH: i32 = 6;
+// The deepest iteration of a search limited only by time or nodes.
+pub const MAX_SEARCH_DEPTH: i32 = 64;
+// Nodes searched between the checks of the time limit.
+pub const SEARCH_TIME_CHECK_NODES: u64 = 1024;
+// Plies with killer moves and principal variations, deeper captures don't need them.
+pub const MAX_PLY: usize = 128;
+// More than the number of cells of any board.
+pub const HISTORY_TABLE_CELLS: usize = 128;
+// All history scores are halved when one of them gets bigger.
+pub const HISTORY_MAX_SCORE: i32 = 1 << 20;
@@ -1,46 +1,98 @@
-mod magic_numbers;
+pub mod magic_numbers;
+pub mod any_game;
+pub mod diagram;
+pub mod fen;
+pub mod flood_fill;
+pub mod international;
+pub mod move_list;
+pub mod moves;
+pub mod pdn;
+pub mod notation;
+pub mod turkish;
+pub mod position;
+pub mod zobrist;
+pub mod position_history;
+pub mod ruleset;
+pub mod validation;
+
+pub use any_game::{AnyGame, AnyMoveUndo};
+pub use diagram::DiagramError;
+pub use fen::FenError;
+pub use international::InternationalGame;
+pub use move_list::MoveList;
+pub use moves::{Move, Piece};
+pub use notation::{MoveNotation, MoveParseError};
+pub use pdn::{PdnError, PdnGame, PdnResult};
+pub use turkish::TurkishGame;
+pub use position::Position;
+pub use position_history::PositionHistory;
+pub use ruleset::Ruleset;
+pub use validation::{MoveError, PositionError};
 use crate::useful_functions::*;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use crate::constants::{PAWN_COST, QUEEN_COST};
-use crate::game::magic_numbers::{MAGIC_NUMBERS, MAGIC_RSHIFT, MAX_POSITION_MAGIC_INDEX, MOVES_WITH_CAPTURES, MOVES_WITHOUT_CAPTURES};
+use crate::constants::*;
+use crate::game::zobrist::{piece_index, ZOBRIST_BLACK_TO_MOVE, ZOBRIST_PIECES};
+#[cfg(not(feature = "flood_fill"))]
+use crate::game::magic_numbers::{magic_index, MOVES_WITH_CAPTURES, MOVES_WITHOUT_CAPTURES};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DrawReason {
+    // The same position with the same player to move occurred three times.
+    Repetition,
+    // Both players have kings and only kings moved without captures for too long
+    // (15 moves in Russian draughts, 40 in English checkers, 25 in international draughts).
+    KingMoves,
+    // Three (or more) kings couldn't catch a single enemy king.
+    ThreeKingsAgainstOne,
+    // The balance of forces in an endgame with kings didn't change for too long.
+    EndgameMoveLimit,
+}
 
-#[derive(Clone, Debug)]
-pub enum Move {
-    Simple(i8, i8),
-    Take(Vec<i8>)
+impl Display for DrawReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            DrawReason::Repetition => "threefold repetition",
+            DrawReason::KingMoves => "only kings moved for too long",
+            DrawReason::ThreeKingsAgainstOne => "three kings against one",
+            DrawReason::EndgameMoveLimit => "endgame move limit",
+        })
+    }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Winner {
     White,
     Black,
-    Draw,
-}
-
-impl ToString for Winner {
-    fn to_string(&self) -> String {
-        match self {
-            Winner::White => "White won!",
-            Winner::Black => "Black won!",
-            Winner::Draw => "Draw",
-        }.to_string()
-    }
+    Draw(DrawReason),
 }
 
-impl Into<Vec<i8>> for Move {
-    fn into(self) -> Vec<i8> {
+impl Display for Winner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Move::Simple(a, b) => vec![a, b],
-            Move::Take(v) => v,
+            Winner::White => write!(f, "White won!"),
+            Winner::Black => write!(f, "Black won!"),
+            Winner::Draw(reason) => write!(f, "Draw: {reason}"),
         }
     }
 }
 
-impl Move {
-    pub fn as_vec(&self) -> Vec<i8> {
-        self.clone().into()
-    }
+// Everything `Game::unmake_move` needs to restore the position before the move.
+#[derive(Clone, Copy, Debug)]
+pub struct MoveUndo {
+    pub from: i8,
+    pub to: i8,
+    // Whether the moving checker was a king before the move.
+    pub was_queen: bool,
+    // Cells of the captured checkers and their colors and kinds.
+    pub captured: u64,
+    pub captured_white: u64,
+    pub captured_queens: u64,
+    pub eval_white: i32,
+    pub key: u64,
+    pub boring_moves_counter: u8,
+    pub balance_moves_counter: u8,
+    pub current_player: bool,
 }
 
 #[derive(Clone)]
@@ -52,9 +104,9 @@ pub enum Checker {
     BlackQueen,
 }
 
-impl Checker {
-    pub fn to_string(&self) -> String {
-        String::from(match self {
+impl Display for Checker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
             Checker::Empty => ".",
             Checker::White => "w",
             Checker::Black => "b",
@@ -64,12 +116,6 @@ impl Checker {
     }
 }
 
-impl Display for Checker {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
-    }
-}
-
 #[derive(Clone)]
 pub struct Game {
     // Bitmasks for game field. If a cell is empty, all corresponding bits must be 0.
@@ -78,60 +124,150 @@ pub struct Game {
     pub is_queen: u64,
     // Game evaluation for white player. Updates dynamically as the game progresses.
     pub eval_white: i32,
+    // Number of half-moves made only by kings since the last capture or pawn move.
     pub boring_moves_counter: u8,
+    // Number of half-moves since the last capture or promotion, i.e. since the balance of forces changed.
+    pub balance_moves_counter: u8,
     // `true` if white, `false` if black.
     pub current_player: bool,
+    // Checkers captured during the current multi-jump (Turkish strike rule). They stay on the board until
+    // the capture sequence ends: they can't be jumped a second time and they block the capturing checker.
+    pub captured: u64,
+    pub ruleset: Ruleset,
+    // Zobrist key of the pieces placement (without the checkers in `captured`) and the player to move.
+    // Updates dynamically, call `update_key` after changing the fields directly.
+    key: u64,
 }
 
 impl Default for Game {
     fn default() -> Self {
-        Game {
-            not_empty: 0b_0101_0101___1010_1010___0101_0101___0000_0000___0000_0000___1010_1010___0101_0101___1010_1010,
-            is_white:  0b_0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___1010_1010___0101_0101___1010_1010,
+        Game::new(Ruleset::default())
+    }
+}
+
+impl Game {
+    pub fn new(ruleset: Ruleset) -> Game {
+        debug_assert!(
+            ruleset.is_played_by_game(),
+            "{ruleset} is played by another type of position"
+        );
+        let (not_empty, is_white) = if ruleset.plays_on_dark_cells() {
+            (
+                0b_0101_0101___1010_1010___0101_0101___0000_0000___0000_0000___1010_1010___0101_0101___1010_1010,
+                0b_0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___1010_1010___0101_0101___1010_1010,
+            )
+        } else {
+            (
+                0b_1010_1010___0101_0101___1010_1010___0000_0000___0000_0000___0101_0101___1010_1010___0101_0101,
+                0b_0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___0101_0101___1010_1010___0101_0101,
+            )
+        };
+        let mut game = Game {
+            not_empty,
+            is_white,
             is_queen:  0b_0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___0000_0000___0000_0000,
             // is_queen:  0b_0101_0101___1010_1010___0101_0101___0000_0000___0000_0000___1010_1010___0101_0101___1010_1010,
             eval_white: 0,
             boring_moves_counter: 0,
-            current_player: true,
-        }
+            balance_moves_counter: 0,
+            current_player: ruleset.first_player(),
+            captured: 0,
+            ruleset,
+            key: 0,
+        };
+        game.update_key();
+        game
     }
 }
 
 impl Hash for Game {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        state.write_u64(self.not_empty);
-        state.write_u64(self.is_white);
-        state.write_u64(self.is_queen);
-        state.write_u8(self.current_player as u8);
+        // The key covers the pieces, the captured checkers and the player, the rest is compared by `eq` too.
+        state.write_u64(self.key);
         state.write_u8(self.boring_moves_counter);
-
+        state.write_u8(self.balance_moves_counter);
+        self.ruleset.hash(state);
     }
 }
 
 impl Game {
     #[inline(always)]
     pub fn is_empty_cell(&self, i: i8) -> bool {
-        return get_bit(self.not_empty, i) == 0;
+        get_bit(self.not_empty, i) == 0
     }
 
     #[inline(always)]
     pub fn is_white_checker(&self, i: i8) -> bool {
-        return get_bit(self.is_white, i) == 1;
+        get_bit(self.is_white, i) == 1
     }
 
     #[inline(always)]
     pub fn is_queen_checker(&self, i: i8) -> bool {
-        return get_bit(self.is_queen, i) == 1;
+        get_bit(self.is_queen, i) == 1
     }
 
     #[inline(always)]
     pub fn change_player(&mut self) {
         self.current_player = !self.current_player;
+        self.key ^= ZOBRIST_BLACK_TO_MOVE;
+    }
+
+    #[inline(always)]
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    // Computes the Zobrist key from scratch.
+    pub fn compute_key(&self) -> u64 {
+        let mut key = if self.current_player { 0 } else { ZOBRIST_BLACK_TO_MOVE };
+        let mut cells = self.not_empty & !self.captured;
+        while cells != 0 {
+            let i = get_bit_i(last_bit(cells));
+            cells &= cells - 1;
+            key ^= Self::get_piece_key(self.is_white_checker(i), self.is_queen_checker(i), i);
+        }
+        key
+    }
+
+    // Needed after building a position by changing the fields.
+    pub fn update_key(&mut self) {
+        self.key = self.compute_key();
+    }
+
+    // Computes `eval_white` from scratch. It is the same as after playing into the position from the start:
+    // the material balance plus how far the pawns have advanced.
+    pub fn compute_eval_white(&self) -> i32 {
+        let mut eval_white = 0;
+        let mut cells = self.not_empty & !self.captured;
+        while cells != 0 {
+            let i = get_bit_i(last_bit(cells));
+            cells &= cells - 1;
+            let is_white = self.is_white_checker(i);
+            let cost = if self.is_queen_checker(i) {
+                QUEEN_COST
+            } else if is_white {
+                PAWN_COST + i as i32
+            } else {
+                PAWN_COST + 63 - i as i32
+            };
+            eval_white += if is_white { cost } else { -cost };
+        }
+        eval_white
+    }
+
+    #[inline(always)]
+    fn get_piece_key(is_white: bool, is_queen: bool, i: i8) -> u64 {
+        ZOBRIST_PIECES[piece_index(is_white, is_queen)][i as usize]
     }
 
     #[inline(always)]
     pub fn evaluate(&self) -> i32 {
-        self.eval_white
+        // In giveaway having fewer checkers is better.
+        if self.ruleset.is_giveaway() {
+            -self.eval_white
+        } else {
+            self.eval_white
+        }
     }
 
     #[inline(always)]
@@ -139,26 +275,75 @@ impl Game {
         self.evaluate() * (self.current_player as i32 * 2 - 1)
     }
 
+    // Checks the draw rules that don't need the game history.
+    pub fn get_draw_reason(&self) -> Option<DrawReason> {
+        match self.ruleset {
+            Ruleset::English => self.get_english_draw_reason(),
+            _ => self.get_russian_draw_reason(),
+        }
+    }
+
     #[inline(always)]
-    pub fn is_draw(&self) -> bool {
-        self.boring_moves_counter >= 15
+    fn both_players_have_queens(&self) -> bool {
+        let queens = self.not_empty & self.is_queen;
+        queens & self.is_white != 0 && queens & !self.is_white != 0
     }
 
-    pub fn get_winner(&self) -> Option<Winner> {
-        let moves = self.get_moves();
-        if moves.is_empty() {
-            return Some(if self.current_player {
-                Winner::Black
+    fn get_english_draw_reason(&self) -> Option<DrawReason> {
+        if self.boring_moves_counter >= ENGLISH_KING_MOVES_DRAW_LIMIT && self.both_players_have_queens() {
+            return Some(DrawReason::KingMoves);
+        }
+        None
+    }
+
+    fn get_russian_draw_reason(&self) -> Option<DrawReason> {
+        // None of the rules can fire earlier, `boring_moves_counter` never exceeds `balance_moves_counter`.
+        if self.balance_moves_counter < THREE_KINGS_ON_MAIN_ROAD_DRAW_LIMIT {
+            return None;
+        }
+        let white = self.not_empty & self.is_white;
+        let black = self.not_empty & !self.is_white;
+        let white_queens = white & self.is_queen;
+        let black_queens = black & self.is_queen;
+        if white_queens == 0 || black_queens == 0 {
+            return None;
+        }
+        if self.boring_moves_counter >= KING_MOVES_DRAW_LIMIT {
+            return Some(DrawReason::KingMoves);
+        }
+        let lone_queen = if white.count_ones() == 1 && black_queens.count_ones() >= 3 {
+            white_queens
+        } else if black.count_ones() == 1 && white_queens.count_ones() >= 3 {
+            black_queens
+        } else {
+            0
+        };
+        if lone_queen != 0 {
+            let limit = if lone_queen & MAIN_ROAD != 0 {
+                THREE_KINGS_ON_MAIN_ROAD_DRAW_LIMIT
             } else {
-                Winner::White
-            });
+                THREE_KINGS_DRAW_LIMIT
+            };
+            if self.balance_moves_counter >= limit {
+                return Some(DrawReason::ThreeKingsAgainstOne);
+            }
         }
-        if self.is_draw() {
-            return Some(Winner::Draw);
+        let limit = match self.not_empty.count_ones() {
+            4..=5 => FOUR_AND_FIVE_PIECES_DRAW_LIMIT,
+            6..=7 => SIX_AND_SEVEN_PIECES_DRAW_LIMIT,
+            _ => return None,
+        };
+        if self.balance_moves_counter >= limit {
+            return Some(DrawReason::EndgameMoveLimit);
         }
         None
     }
 
+    #[inline(always)]
+    pub fn is_draw(&self) -> bool {
+        self.get_draw_reason().is_some()
+    }
+
     fn make_pawn_move(&mut self, from: i8, to: i8) {
         let from_mask = 1 << from;
         let to_mask = 1 << to;
@@ -170,21 +355,39 @@ impl Game {
         self.is_queen ^= (is_queen_from_bit >> from << to) ^ is_queen_from_bit;
         self.eval_white += ((to - from) * (is_queen_from_bit == 0) as i8) as i32;
         // self.eval_white += (to - from) as i32;
-        // Promotion to queen
-        if (to > 55 && self.current_player || to < 8 && !self.current_player) && is_queen_from_bit == 0 {
+        let piece_keys = &ZOBRIST_PIECES[piece_index(is_white_from_bit != 0, is_queen_from_bit != 0)];
+        self.key ^= piece_keys[from as usize] ^ piece_keys[to as usize];
+    }
+
+    // The last row for the current player.
+    #[inline(always)]
+    fn is_promotion_cell(&self, i: i8) -> bool {
+        i > 55 && self.current_player || i < 8 && !self.current_player
+    }
+
+    #[inline(always)]
+    fn get_piece(&self, i: i8) -> Piece {
+        if self.is_queen_checker(i) {
+            Piece::Queen
+        } else {
+            Piece::Pawn
+        }
+    }
+
+    // Promotes the pawn standing on the cell if it is on the last row.
+    fn try_promote(&mut self, to: i8) {
+        let to_mask = 1 << to;
+        if self.is_promotion_cell(to) && self.is_queen & to_mask == 0 {
             self.is_queen ^= to_mask;
+            self.key ^= Self::get_piece_key(self.current_player, false, to) ^ Self::get_piece_key(self.current_player, true, to);
             let player_coeff = ((self.current_player as i32) << 1) - 1;
             self.eval_white += player_coeff * (QUEEN_COST - PAWN_COST);
             self.eval_white += 63 * (!self.current_player) as i32 - to as i32;
         }
     }
 
-    fn make_cutting_move(&mut self, from: i8, to: i8) {
-        #[cfg(debug_assertions)]
-        {
-            assert_eq!(get_bit(self.not_empty, from), 1);
-            assert_eq!(get_bit(self.not_empty, to), 0);
-        }
+    // The first checker between the cells of a jump.
+    fn get_captured_cell(not_empty: u64, from: i8, to: i8) -> i8 {
         let mut curr_cell = from;
         let diff = to - from;
         let delta = diff.signum() * if diff % 7 == 0 {
@@ -195,16 +398,65 @@ impl Game {
             9
         };
         let mut it = 0;
-        let captured_cell = loop {
+        loop {
             curr_cell += delta;
-            if get_bit(self.not_empty, curr_cell) == 1 {
+            if get_bit(not_empty, curr_cell) == 1 {
                 break curr_cell;
             }
             it += 1;
             if it > 10 {
                 panic!("Incorrect move: {from} {to}");
             }
-        };
+        }
+    }
+
+    // Makes one jump of a capture sequence. The captured checker is only marked in `captured`,
+    // call `finish_capture` when the sequence is over.
+    pub fn make_cutting_move(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        let captured_cell = self.find_jumped_cell(from, to).ok_or(MoveError::IllegalJump(from, to))?;
+        self.make_jump(from, to, captured_cell);
+        Ok(())
+    }
+
+    // The checker taken by a jump of the current player, if the jump is possible: exactly one enemy checker,
+    // not captured yet, between the cells, and no farther than the next cell for pawns and short kings.
+    fn find_jumped_cell(&self, from: i8, to: i8) -> Option<i8> {
+        if !(0..64).contains(&from) || !(0..64).contains(&to) || self.is_empty_cell(from) || !self.is_empty_cell(to) {
+            return None;
+        }
+        if self.is_white_checker(from) != self.current_player || get_bit(self.captured, from) == 1 {
+            return None;
+        }
+        let (fx, fy) = conv_1d_to_2d(from);
+        let (tx, ty) = conv_1d_to_2d(to);
+        let distance = fx.abs_diff(tx);
+        let flies = self.is_queen_checker(from) && self.ruleset.has_flying_kings();
+        if distance != fy.abs_diff(ty) || distance < 2 || distance > 2 && !flies {
+            return None;
+        }
+        let mut jumped = None;
+        for k in 1..distance {
+            let x = if tx > fx { fx + k } else { fx - k };
+            let y = if ty > fy { fy + k } else { fy - k };
+            let i = conv_2d_to_1d(x, y);
+            if !self.is_empty_cell(i) {
+                if jumped.is_some() {
+                    return None;
+                }
+                jumped = Some(i);
+            }
+        }
+        jumped.filter(|&i| self.is_white_checker(i) != self.current_player && get_bit(self.captured, i) == 0)
+    }
+
+    // `make_cutting_move` with the captured checker already known.
+    fn make_jump(&mut self, from: i8, to: i8, captured_cell: i8) {
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(get_bit(self.not_empty, from), 1);
+            assert_eq!(get_bit(self.not_empty, to), 0);
+            assert_eq!(get_bit(self.not_empty, captured_cell), 1);
+        }
         let curr_player_add_eval = if get_bit(self.is_queen, captured_cell) == 1 {
             QUEEN_COST
         } else {
@@ -213,31 +465,98 @@ impl Game {
         };
         let player_coeff = ((self.current_player as i32) << 1) - 1;
         self.eval_white += player_coeff * curr_player_add_eval;
+        self.key ^= Self::get_piece_key(self.is_white_checker(captured_cell), self.is_queen_checker(captured_cell), captured_cell);
         self.make_pawn_move(from, to);
-        // Clear the cell with the captured checker
-        let mask = !(1 << captured_cell);
+        if self.ruleset.promotes_during_capture() {
+            self.try_promote(to);
+        }
+        self.captured |= 1 << captured_cell;
+    }
+
+    // Takes back a jump made by `make_cutting_move`. The other arguments are the values before the jump.
+    #[inline(always)]
+    fn unmake_cutting_move(&mut self, from: i8, to: i8, was_queen: bool, captured: u64, eval_white: i32, key: u64) {
+        self.make_pawn_move(to, from);
+        if !was_queen {
+            self.is_queen &= !(1 << from);
+        }
+        self.captured = captured;
+        self.eval_white = eval_white;
+        self.key = key;
+    }
+
+    // Clears the cells with the checkers captured during the finished capture sequence
+    // and promotes the capturing pawn if promotion was deferred until the end of the move.
+    pub fn finish_capture(&mut self, to: i8) {
+        let mask = !self.captured;
         self.is_white &= mask;
         self.not_empty &= mask;
         self.is_queen &= mask;
+        self.captured = 0;
+        if !self.ruleset.promotes_during_capture() {
+            self.try_promote(to);
+        }
+        self.boring_moves_counter = 0;
+        self.balance_moves_counter = 0;
     }
 
-    pub fn make_move(&mut self, move_to_make: Move) {
-        match move_to_make {
-            Move::Simple(a, b) => {
-                if get_bit(self.is_queen, a) == 1 {
-                    self.boring_moves_counter += 1;
-                } else {
-                    self.boring_moves_counter = 0;
-                }
-                self.make_pawn_move(a, b)
-            },
-            Move::Take(v) => {
-                self.boring_moves_counter = 0;
-                for i in 1..v.len() {
-                    self.make_cutting_move(v[i - 1], v[i]);
-                }
+    pub fn make_move(&mut self, move_to_make: Move) -> MoveUndo {
+        let (from, to) = (move_to_make.from(), move_to_make.to());
+        let mut undo = MoveUndo {
+            from,
+            to,
+            was_queen: self.is_queen_checker(from),
+            captured: 0,
+            captured_white: 0,
+            captured_queens: 0,
+            eval_white: self.eval_white,
+            key: self.key,
+            boring_moves_counter: self.boring_moves_counter,
+            balance_moves_counter: self.balance_moves_counter,
+            current_player: self.current_player,
+        };
+        if move_to_make.is_capture() {
+            let path = move_to_make.path();
+            for (i, &captured_cell) in move_to_make.captured().iter().enumerate() {
+                self.make_jump(path[i], path[i + 1], captured_cell);
+            }
+            undo.captured = self.captured;
+            undo.captured_white = self.captured & self.is_white;
+            undo.captured_queens = self.captured & self.is_queen;
+            self.finish_capture(to);
+        } else if get_bit(self.is_queen, from) == 1 {
+            self.boring_moves_counter = self.boring_moves_counter.saturating_add(1);
+            self.balance_moves_counter = self.balance_moves_counter.saturating_add(1);
+            self.make_pawn_move(from, to);
+        } else {
+            self.boring_moves_counter = 0;
+            self.make_pawn_move(from, to);
+            self.try_promote(to);
+            if get_bit(self.is_queen, to) == 1 {
+                self.balance_moves_counter = 0;
+            } else {
+                self.balance_moves_counter = self.balance_moves_counter.saturating_add(1);
             }
         }
+        undo
+    }
+
+    // Restores the position before the move that returned `undo`, including the player to move.
+    pub fn unmake_move(&mut self, undo: MoveUndo) {
+        let from_mask = 1 << undo.from;
+        let to_mask = 1 << undo.to;
+        let is_white = self.is_white & to_mask != 0;
+        self.not_empty &= !to_mask;
+        self.is_white &= !to_mask;
+        self.is_queen &= !to_mask;
+        self.not_empty |= from_mask | undo.captured;
+        self.is_white |= undo.captured_white | if is_white { from_mask } else { 0 };
+        self.is_queen |= undo.captured_queens | if undo.was_queen { from_mask } else { 0 };
+        self.eval_white = undo.eval_white;
+        self.key = undo.key;
+        self.boring_moves_counter = undo.boring_moves_counter;
+        self.balance_moves_counter = undo.balance_moves_counter;
+        self.current_player = undo.current_player;
     }
 
     #[inline(always)]
@@ -246,235 +565,437 @@ impl Game {
     }
 
     #[inline(always)]
-    fn get_pawns_left_up_takes_mask(&self, current: u64, enemy: u64) -> u64 {
-        current & (enemy >> 9) & (!self.not_empty >> 18) & EXCLUDE_2_LEFT_COLUMNS
+    fn get_pawns_left_up_takes_mask(current: u64, enemy: u64, empty: u64) -> u64 {
+        current & (enemy >> 9) & (empty >> 18) & EXCLUDE_2_LEFT_COLUMNS
     }
 
     #[inline(always)]
-    fn get_pawns_right_up_takes_mask(&self, current: u64, enemy: u64) -> u64 {
-        current & (enemy >> 7) & (!self.not_empty >> 14) & EXCLUDE_2_RIGHT_COLUMNS
+    fn get_pawns_right_up_takes_mask(current: u64, enemy: u64, empty: u64) -> u64 {
+        current & (enemy >> 7) & (empty >> 14) & EXCLUDE_2_RIGHT_COLUMNS
     }
 
     #[inline(always)]
-    fn get_pawns_left_down_takes_mask(&self, current: u64, enemy: u64) -> u64 {
-        current & (enemy << 7) & (!self.not_empty << 14) & EXCLUDE_2_LEFT_COLUMNS
+    fn get_pawns_left_down_takes_mask(current: u64, enemy: u64, empty: u64) -> u64 {
+        current & (enemy << 7) & (empty << 14) & EXCLUDE_2_LEFT_COLUMNS
     }
 
     #[inline(always)]
-    fn get_pawns_right_down_takes_mask(&self, current: u64, enemy: u64) -> u64 {
-        current & (enemy << 9) & (!self.not_empty << 18) & EXCLUDE_2_RIGHT_COLUMNS
+    fn get_pawns_right_down_takes_mask(current: u64, enemy: u64, empty: u64) -> u64 {
+        current & (enemy << 9) & (empty << 18) & EXCLUDE_2_RIGHT_COLUMNS
     }
 
-    pub fn get_takes_from_cell_rev(&self, from: i8) -> Vec<Vec<i8>> {
-        let enemy = self.not_empty
+    // Checkers of the player to move can capture, including the ones already captured in this move.
+    #[inline(always)]
+    fn get_enemy(&self) -> u64 {
+        self.not_empty
             & if self.current_player {
                 !self.is_white
             } else {
                 self.is_white
-            };
+            }
+    }
+
+    // Cells the checker standing on `from` can land on after capturing one of `enemy`.
+    // `occupied` must not include the capturing checker itself.
+    #[inline(always)]
+    fn get_jump_targets(&self, from: i8, is_queen: bool, occupied: u64, enemy: u64) -> u64 {
         let cell_mask = 1 << from;
-        let mut moves = Vec::new();
-        if self.is_queen & cell_mask == 0 {
-            if self.get_pawns_left_up_takes_mask(cell_mask, enemy) != 0 {
-                moves.push(from + 18);
+        if !is_queen || !self.ruleset.has_flying_kings() {
+            let enemy = if is_queen || self.ruleset.pawns_capture_kings() {
+                enemy
+            } else {
+                enemy & !self.is_queen
+            };
+            let (up, down) = if is_queen || self.ruleset.pawns_capture_backwards() {
+                (true, true)
+            } else {
+                (self.current_player, !self.current_player)
+            };
+            let empty = !occupied;
+            let mut can_move_to = 0;
+            if up && Self::get_pawns_left_up_takes_mask(cell_mask, enemy, empty) != 0 {
+                can_move_to |= cell_mask << 18;
             }
-            if self.get_pawns_right_up_takes_mask(cell_mask, enemy) != 0 {
-                moves.push(from + 14);
+            if up && Self::get_pawns_right_up_takes_mask(cell_mask, enemy, empty) != 0 {
+                can_move_to |= cell_mask << 14;
             }
-            if self.get_pawns_left_down_takes_mask(cell_mask, enemy) != 0 {
-                moves.push(from - 14);
+            if down && Self::get_pawns_left_down_takes_mask(cell_mask, enemy, empty) != 0 {
+                can_move_to |= cell_mask >> 14;
             }
-            if self.get_pawns_right_down_takes_mask(cell_mask, enemy) != 0 {
-                moves.push(from - 18);
+            if down && Self::get_pawns_right_down_takes_mask(cell_mask, enemy, empty) != 0 {
+                can_move_to |= cell_mask >> 18;
             }
-            // let me = !enemy & self.not_empty;
-            // let diagonal = DIAGONALS[from as usize];
-            // let magic_num = MAGIC_NUMBERS[from as usize];
-            // let blockers: u64 = me & diagonal;
-            // let magic_i = ((blockers.wrapping_mul(magic_num)) >> MAGIC_RSHIFT) as usize;
-            // let before_blocker = MOVES_WITHOUT_CAPTURES[MAX_POSITION_MAGIC_INDEX * from as usize + magic_i];
-            // let cells_to_capture: u64 = enemy & diagonal;
-            // let magic_i = ((cells_to_capture.wrapping_mul(magic_num)) >> MAGIC_RSHIFT) as usize;
-            // let after_capture = MOVES_WITH_CAPTURES[MAX_POSITION_MAGIC_INDEX * from as usize + magic_i];
-            // assert_ne!(before_blocker, 1);
-            // assert_ne!(after_capture, 1);
-            // let mut can_move_to = (before_blocker & after_capture) & DIAGONALS_LIMITED_BY_2[from as usize];
-            // while can_move_to != 0 {
-            //     let mask = last_bit(can_move_to);
-            //     can_move_to ^= mask;
-            //     let j = get_bit_i(mask);
-            //     moves.push(j);
-            // }
+            can_move_to
         } else {
-            let me = !enemy & self.not_empty;
-            let diagonal = DIAGONALS[from as usize];
-            let magic_num = MAGIC_NUMBERS[from as usize];
-            let blockers: u64 = me & diagonal;
-            let magic_i = ((blockers.wrapping_mul(magic_num)) >> MAGIC_RSHIFT) as usize;
-            let before_blocker = MOVES_WITHOUT_CAPTURES[MAX_POSITION_MAGIC_INDEX * from as usize + magic_i];
-            let cells_to_capture: u64 = enemy & diagonal;
-            let magic_i = ((cells_to_capture.wrapping_mul(magic_num)) >> MAGIC_RSHIFT) as usize;
-            let after_capture = MOVES_WITH_CAPTURES[MAX_POSITION_MAGIC_INDEX * from as usize + magic_i];
-            #[cfg(debug_assertions)]{
-                assert_ne!(before_blocker, 1);
-                assert_ne!(after_capture, 1);
-            }
-            let mut can_move_to = before_blocker & after_capture;
-            while can_move_to != 0 {
-                let mask = last_bit(can_move_to);
-                can_move_to ^= mask;
-                let j = get_bit_i(mask);
-                moves.push(j);
+            let can_move_to = Self::get_flying_jump_targets(from, occupied, enemy);
+            Self::keep_continuing_landings(from, can_move_to, occupied, enemy)
+        }
+    }
+
+    // Cells a flying king on `from` can move to, before the first of `checkers` in every direction.
+    #[cfg(not(feature = "flood_fill"))]
+    #[inline(always)]
+    fn get_king_slide_targets(from: i8, checkers: u64) -> u64 {
+        MOVES_WITHOUT_CAPTURES[magic_index(from, checkers & DIAGONALS[from as usize])]
+    }
+
+    #[cfg(feature = "flood_fill")]
+    #[inline(always)]
+    fn get_king_slide_targets(from: i8, checkers: u64) -> u64 {
+        flood_fill::king_slide_targets(from, checkers)
+    }
+
+    // Cells behind the first of `enemy` in every direction, before the second one.
+    #[cfg(not(feature = "flood_fill"))]
+    #[inline(always)]
+    fn get_king_jump_targets(from: i8, enemy: u64) -> u64 {
+        MOVES_WITH_CAPTURES[magic_index(from, enemy & DIAGONALS[from as usize])]
+    }
+
+    #[cfg(feature = "flood_fill")]
+    #[inline(always)]
+    fn get_king_jump_targets(from: i8, enemy: u64) -> u64 {
+        flood_fill::king_jump_targets(from, enemy)
+    }
+
+    // Cells a flying king on `from` can land on after capturing one of `enemy`, in all directions.
+    #[inline(always)]
+    fn get_flying_jump_targets(from: i8, occupied: u64, enemy: u64) -> u64 {
+        let me = !enemy & (occupied | 1 << from);
+        Self::get_king_slide_targets(from, me) & Self::get_king_jump_targets(from, enemy)
+    }
+
+    // A king must stop on a cell from which it can capture further, if there is one behind the captured checker.
+    // Removes the other landing cells in such directions.
+    fn keep_continuing_landings(from: i8, can_move_to: u64, occupied: u64, enemy: u64) -> u64 {
+        let mut continuing = 0;
+        let mut continuing_captures = 0;
+        let mut mask = can_move_to;
+        while mask != 0 {
+            let to_mask = last_bit(mask);
+            mask ^= to_mask;
+            let to = get_bit_i(to_mask);
+            let captured_mask = 1 << Self::get_captured_cell(occupied, from, to);
+            if Self::get_flying_jump_targets(to, occupied, enemy & !captured_mask) != 0 {
+                continuing |= to_mask;
+                continuing_captures |= captured_mask;
             }
         }
-        let mut new_moves: Vec<Vec<i8>> = Vec::new();
-        for to in moves {
-            let mut game_copy = self.clone();
-            // dbg!(&self);
-            // println!("{from} {to}");
-            game_copy.make_cutting_move(from, to);
-            // dbg!(&self);
-            let part2 = game_copy.get_takes_from_cell_rev(to);
-            if part2.is_empty() {
-                new_moves.push(vec![to, from]);
-            } else {
-                new_moves.extend(part2.into_iter().map(|mut m| {
-                    m.push(from);
-                    m
-                }));
+        if continuing_captures == 0 {
+            return can_move_to;
+        }
+        let mut result = continuing;
+        let mut mask = can_move_to & !continuing;
+        while mask != 0 {
+            let to_mask = last_bit(mask);
+            mask ^= to_mask;
+            if continuing_captures & 1 << Self::get_captured_cell(occupied, from, get_bit_i(to_mask)) == 0 {
+                result |= to_mask;
             }
         }
-        new_moves
+        result
     }
 
-    #[inline(always)]
-    pub fn get_cuts_from_cell(&self, i: i8) -> Vec<Move> {
-        let mut res = self.get_takes_from_cell_rev(i);
-        res.iter_mut().for_each(|m| m.reverse());
-        res.into_iter().map(|m| Move::Take(m)).collect()
+    pub fn get_cuts_from_cell(&self, i: i8) -> MoveList {
+        let mut res = MoveList::new();
+        self.generate_takes_from_cell(i, &mut res);
+        res
+    }
+
+    // Adds every capture sequence of the checker on the cell to `moves`. The sequences are walked depth-first
+    // with a stack on the array, without changing the position: the captured checkers stay on the board
+    // until the move ends anyway, so only the capturing checker and the captured cells have to be tracked.
+    fn generate_takes_from_cell(&self, from: i8, moves: &mut MoveList) {
+        let piece = self.get_piece(from);
+        let occupied = self.not_empty & !(1 << from);
+        let enemy = self.get_enemy();
+        let mut captured = self.captured;
+        let mut current = Move::new_capture(from, piece);
+        // For every jump of the sequence: the cells left to jump to and whether the checker is a king there.
+        let mut stack = [(0, false); MAX_CAPTURES + 1];
+        stack[0] = (self.get_jump_targets(from, piece == Piece::Queen, occupied, enemy & !captured), piece == Piece::Queen);
+        let mut depth = 0;
+        loop {
+            let (can_move_to, is_queen) = stack[depth];
+            if can_move_to == 0 {
+                if depth == 0 {
+                    break;
+                }
+                // All continuations of the last jump are explored.
+                captured &= !(1 << current.captured()[depth - 1]);
+                current.pop_capture();
+                depth -= 1;
+                continue;
+            }
+            let mask = last_bit(can_move_to);
+            stack[depth].0 ^= mask;
+            let to = get_bit_i(mask);
+            let captured_cell = Self::get_captured_cell(occupied, current.to(), to);
+            captured |= 1 << captured_cell;
+            current.push_capture(to, captured_cell);
+            let promoted = !is_queen && self.ruleset.promotes_during_capture() && self.is_promotion_cell(to);
+            let next = if promoted && self.ruleset.promotion_ends_move() {
+                0
+            } else {
+                self.get_jump_targets(to, is_queen || promoted, occupied, enemy & !captured)
+            };
+            if next == 0 {
+                // No jumps from here, so the capture ends on this cell.
+                let mut res = current;
+                res.promotion = piece == Piece::Pawn && (is_queen || promoted || self.is_promotion_cell(to));
+                if self.ruleset.has_majority_capture() {
+                    moves.push_majority_capture(res);
+                } else {
+                    moves.push(res);
+                }
+                captured &= !(1 << captured_cell);
+                current.pop_capture();
+            } else {
+                depth += 1;
+                stack[depth] = (next, is_queen || promoted);
+            }
+        }
     }
 
-    pub fn get_moves_with_takes(&self) -> Vec<Move> {
+    // Pawns that have at least one capture and all the kings of the player to move.
+    fn get_capture_candidates(&self) -> u64 {
         let is_mine = if self.current_player {
             self.is_white
         } else {
             !self.is_white
         };
         let my = self.get_pawns() & is_mine;
-        let enemy = self.not_empty & !is_mine;
+        let (my_up, my_down) = if self.ruleset.pawns_capture_backwards() {
+            (my, my)
+        } else if self.current_player {
+            (my, 0)
+        } else {
+            (0, my)
+        };
+        let enemy = self.not_empty & !self.captured & !is_mine;
+        let pawns_enemy = if self.ruleset.pawns_capture_kings() {
+            enemy
+        } else {
+            enemy & !self.is_queen
+        };
         let empty_cells = !self.not_empty;
-        let masks = [
-            (
-                7,
-                (enemy >> 7) & (empty_cells >> 14) & EXCLUDE_2_RIGHT_COLUMNS & my,
-            ),
-            (
-                -9,
-                (enemy << 9) & (empty_cells << 18) & EXCLUDE_2_RIGHT_COLUMNS & my,
-            ),
-            (
-                9,
-                (enemy >> 9) & (empty_cells >> 18) & EXCLUDE_2_LEFT_COLUMNS & my,
-            ),
-            (
-                -7,
-                (enemy << 7) & (empty_cells << 14) & EXCLUDE_2_LEFT_COLUMNS & my,
-            ),
-        ];
-        let mut moves = Vec::new();
-        for (add, mut mask) in masks {
-            while mask != 0 {
-                // i is current cell number
-                let last_bit = last_bit(mask);
-                let i = get_bit_i(last_bit);
-                mask &= !last_bit;
-                let from = i;
-                let to = i + 2 * add;
-                let mut game_copy = self.clone();
-                game_copy.make_cutting_move(from, to);
-                let part2 = game_copy.get_takes_from_cell_rev(i + 2 * add);
-                if part2.is_empty() {
-                    moves.push(Move::Take(vec![from, to]));
-                } else {
-                    part2.into_iter().for_each(|mut m| {
-                        m.pop();
-                        m.push(to);
-                        m.push(from);
-                        m.reverse();
-                        moves.push(Move::Take(m));
-                    });
-                }
-            }
-        }
-        let mut cells_to_consider = is_mine & self.not_empty & self.is_queen;
+        Self::get_pawns_left_up_takes_mask(my_up, pawns_enemy, empty_cells)
+            | Self::get_pawns_right_up_takes_mask(my_up, pawns_enemy, empty_cells)
+            | Self::get_pawns_left_down_takes_mask(my_down, pawns_enemy, empty_cells)
+            | Self::get_pawns_right_down_takes_mask(my_down, pawns_enemy, empty_cells)
+            | is_mine & self.not_empty & self.is_queen
+    }
+
+    // Adds the allowed captures to `moves`, which must be empty.
+    pub fn generate_moves_with_takes(&self, moves: &mut MoveList) {
+        let mut cells_to_consider = self.get_capture_candidates();
         while cells_to_consider != 0 {
             // i is current cell number
             let last_bit = last_bit(cells_to_consider);
             let i = get_bit_i(last_bit);
             cells_to_consider &= !last_bit;
-            moves.extend(self.get_cuts_from_cell(i));
+            self.generate_takes_from_cell(i, moves);
         }
+        moves.retain(self.get_takes_filter(moves));
+    }
+
+    pub fn get_moves_with_takes(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        self.generate_moves_with_takes(&mut moves);
         moves
     }
 
-    pub fn get_moves_without_takes(&self) -> Vec<Move> {
+    // The recursive capture generator that makes the jumps in place. It is slower than `get_moves_with_takes`
+    // and kept as a reference for the tests and benchmarks.
+    #[doc(hidden)]
+    pub fn get_moves_with_takes_recursive(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let mut game = self.clone();
+        let mut cells_to_consider = self.get_capture_candidates();
+        while cells_to_consider != 0 {
+            let last_bit = last_bit(cells_to_consider);
+            let i = get_bit_i(last_bit);
+            cells_to_consider &= !last_bit;
+            let mut current = Move::new_capture(i, self.get_piece(i));
+            game.find_takes(&mut current, &mut moves);
+        }
+        moves.retain(self.get_takes_filter(&moves));
+        moves
+    }
+
+    // Extends the capture `current` with every jump from its last cell. Finished captures are added to `result`.
+    fn find_takes(&mut self, current: &mut Move, result: &mut Vec<Move>) {
+        let from = current.to();
+        // Already captured checkers can't be taken again, so they are treated as blockers.
+        let enemy = self.get_enemy() & !self.captured;
+        let occupied = self.not_empty & !(1 << from);
+        let mut can_move_to = self.get_jump_targets(from, self.is_queen_checker(from), occupied, enemy);
+        while can_move_to != 0 {
+            let mask = last_bit(can_move_to);
+            can_move_to ^= mask;
+            self.find_takes_after_jump(from, get_bit_i(mask), current, result);
+        }
+    }
+
+    // Makes the jump in place, explores the rest of the sequence and takes the jump back.
+    fn find_takes_after_jump(&mut self, from: i8, to: i8, current: &mut Move, result: &mut Vec<Move>) {
+        let (was_queen, captured, eval_white, key) = (self.is_queen_checker(from), self.captured, self.eval_white, self.key);
+        let captured_cell = Self::get_captured_cell(self.not_empty, from, to);
+        self.make_jump(from, to, captured_cell);
+        current.push_capture(to, captured_cell);
+        let found = result.len();
+        if was_queen || !self.is_queen_checker(to) || !self.ruleset.promotion_ends_move() {
+            self.find_takes(current, result);
+        }
+        // No jumps from here, so the capture ends on this cell.
+        if result.len() == found {
+            let mut res = *current;
+            res.promotion = res.piece == Piece::Pawn && (self.is_queen_checker(to) || self.is_promotion_cell(to));
+            result.push(res);
+        }
+        current.pop_capture();
+        self.unmake_cutting_move(from, to, was_queen, captured, eval_white, key);
+    }
+
+    // Decides which of the found captures are allowed: the longest ones with the majority rule,
+    // then the ones preferred by the Italian priorities.
+    fn get_takes_filter<'a>(&'a self, moves: &[Move]) -> impl Fn(&Move) -> bool + 'a {
+        let max_len = if self.ruleset.has_majority_capture() {
+            moves.iter().map(|m| m.captured().len()).max().unwrap_or(0)
+        } else {
+            0
+        };
+        let is_long_enough = move |m: &Move| !self.ruleset.has_majority_capture() || m.captured().len() == max_len;
+        let max_key = if self.ruleset.has_king_capture_priority() {
+            moves.iter().filter(|m| is_long_enough(m)).map(|m| self.get_capture_priority(m)).max()
+        } else {
+            None
+        };
+        move |m| is_long_enough(m) && max_key.is_none_or(|key| self.get_capture_priority(m) == key)
+    }
+
+    // Greater is preferred: capturing with a king, number of captured kings, then the kings captured earliest.
+    // The last value has a bit for every captured king, the earlier ones in the higher bits.
+    fn get_capture_priority(&self, capture: &Move) -> (bool, u32, u32) {
+        let mut captured_queens: u32 = 0;
+        for (i, &cell) in capture.captured().iter().enumerate() {
+            if self.is_queen_checker(cell) {
+                captured_queens |= 1 << (MAX_CAPTURES - 1 - i);
+            }
+        }
+        (capture.piece == Piece::Queen, captured_queens.count_ones(), captured_queens)
+    }
+
+    #[inline(always)]
+    fn get_simple_move(&self, from: i8, to: i8) -> Move {
+        let piece = self.get_piece(from);
+        Move::new_simple(from, to, piece, piece == Piece::Pawn && self.is_promotion_cell(to))
+    }
+
+    // Adds the moves without captures to `moves`.
+    pub fn generate_moves_without_takes(&self, moves: &mut MoveList) {
         let my_pieces = if self.current_player {
             self.is_white & self.not_empty
         } else {
             !self.is_white & self.not_empty
         };
         let my_pawns = !self.is_queen & my_pieces;
+        let (my_flying_queens, my_short_queens) = if self.ruleset.has_flying_kings() {
+            (my_pieces & self.is_queen, 0)
+        } else {
+            (0, my_pieces & self.is_queen)
+        };
         let empty = !self.not_empty;
-        // Pawns
-        let masks = if self.current_player {
-            [
-                (7, (empty >> 7) & EXCLUDE_RIGHT_COLUMN & my_pawns),
-                (9, (empty >> 9) & EXCLUDE_LEFT_COLUMN & my_pawns),
-            ]
+        // Pawns and kings that move only to the neighbouring cells
+        let (my_up, my_down) = if self.current_player {
+            (my_pawns | my_short_queens, my_short_queens)
         } else {
-            [
-                (-7, (empty << 7) & EXCLUDE_LEFT_COLUMN & my_pawns),
-                (-9, (empty << 9) & EXCLUDE_RIGHT_COLUMN & my_pawns),
-            ]
+            (my_short_queens, my_pawns | my_short_queens)
         };
-        let mut moves = Vec::new();
+        let masks = [
+            (7, (empty >> 7) & EXCLUDE_RIGHT_COLUMN & my_up),
+            (9, (empty >> 9) & EXCLUDE_LEFT_COLUMN & my_up),
+            (-7, (empty << 7) & EXCLUDE_LEFT_COLUMN & my_down),
+            (-9, (empty << 9) & EXCLUDE_RIGHT_COLUMN & my_down),
+        ];
         for (add, mut mask) in masks {
             while mask != 0 {
                 // i is current cell number
                 let last_bit = last_bit(mask);
                 let i = get_bit_i(last_bit);
                 mask &= !last_bit;
-                moves.push(Move::Simple(i, i + add));
+                moves.push(self.get_simple_move(i, i + add));
             }
         }
-        // Queens
-        let mut my_queens = my_pieces & self.is_queen;
+        // Flying queens
+        let mut my_queens = my_flying_queens;
         while my_queens != 0 {
             let curr_queen_mask = last_bit(my_queens);
             let coord = get_bit_i(curr_queen_mask);
             my_queens ^= curr_queen_mask;
-            let blockers: u64 = self.not_empty & DIAGONALS[coord as usize];
-            let magic_i = ((blockers.wrapping_mul(MAGIC_NUMBERS[coord as usize])) >> MAGIC_RSHIFT) as usize;
-            let mut can_move_to = MOVES_WITHOUT_CAPTURES[MAX_POSITION_MAGIC_INDEX * coord as usize + magic_i];
-            #[cfg(debug_assertions)]
-            assert_ne!(can_move_to, 1, "{coord} {blockers:b} {magic_i} {can_move_to:b}");
+            let mut can_move_to = Self::get_king_slide_targets(coord, self.not_empty);
             while can_move_to != 0 {
                 let mask = last_bit(can_move_to);
                 can_move_to ^= mask;
                 let j = get_bit_i(mask);
-                moves.push(Move::Simple(coord, j));
+                moves.push(Move::new_simple(coord, j, Piece::Queen, false));
             }
         }
+    }
+
+    pub fn get_moves_without_takes(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        self.generate_moves_without_takes(&mut moves);
         moves
     }
 
-    // bool value is true if player can cut
-    pub fn get_moves(&self) -> Vec<Move> {
-        let moves_with_cutting = self.get_moves_with_takes();
-        if !moves_with_cutting.is_empty() {
-            return moves_with_cutting;
+    // Adds the legal moves to `moves`, which must be empty. Captures are mandatory.
+    pub fn generate_moves(&self, moves: &mut MoveList) {
+        self.generate_moves_with_takes(moves);
+        if moves.is_empty() {
+            self.generate_moves_without_takes(moves);
+        }
+    }
+
+    pub fn get_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        self.generate_moves(&mut moves);
+        moves
+    }
+
+    // Number of move sequences of length `depth` from this position. Draw rules are ignored.
+    // Used to test move generation against known counts.
+    pub fn perft(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let mut moves = MoveList::new();
+        self.generate_moves(&mut moves);
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+        let mut result = 0;
+        for &m in &moves {
+            let undo = self.make_move(m);
+            self.change_player();
+            result += self.perft(depth - 1);
+            self.unmake_move(undo);
+        }
+        result
+    }
+
+    // `perft` split by the root moves, to find which move has a wrong count.
+    pub fn perft_divide(&mut self, depth: u32) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return vec![];
         }
-        self.get_moves_without_takes()
+        self.get_moves().into_iter().map(|m| {
+            let undo = self.make_move(m);
+            self.change_player();
+            let count = self.perft(depth - 1);
+            self.unmake_move(undo);
+            (m, count)
+        }).collect()
     }
 
     pub fn get_data(&self) -> Vec<Vec<Checker>> {
@@ -504,7 +1025,10 @@ impl Game {
 
 impl PartialEq for Game {
     fn eq(&self, other: &Self) -> bool {
-        let res = (self.not_empty, self.is_white, self.is_queen, self.current_player, self.boring_moves_counter) == (other.not_empty, other.is_white, other.is_queen, other.current_player, self.boring_moves_counter);
+        let res = (self.not_empty, self.is_white, self.is_queen, self.captured, self.current_player)
+            == (other.not_empty, other.is_white, other.is_queen, other.captured, other.current_player)
+            && (self.boring_moves_counter, self.balance_moves_counter, self.ruleset)
+                == (other.boring_moves_counter, other.balance_moves_counter, other.ruleset);
         #[cfg(debug_assertions)]
         if res {
             assert_eq!(self.eval_white, other.eval_white);
@@ -519,8 +1043,7 @@ impl Display for Game {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(
             f,
-            "{}\n{}\n{}'s move",
-            "  a b c d e f g h",
+            "  a b c d e f g h\n{}\n{}'s move",
             self.get_data()
                 .iter()
                 .map(|row| row
@@ -537,6 +1060,100 @@ impl Display for Game {
     }
 }
 
+impl Position for Game {
+    type Undo = MoveUndo;
+
+    #[inline(always)]
+    fn current_player(&self) -> bool {
+        self.current_player
+    }
+
+    #[inline(always)]
+    fn change_player(&mut self) {
+        Game::change_player(self)
+    }
+
+    #[inline(always)]
+    fn evaluate_for_me(&self) -> i32 {
+        Game::evaluate_for_me(self)
+    }
+
+    #[inline(always)]
+    fn boring_moves_counter(&self) -> u8 {
+        self.boring_moves_counter
+    }
+
+    #[inline(always)]
+    fn is_same_position(&self, other: &Game) -> bool {
+        (self.not_empty, self.is_white, self.is_queen, self.current_player)
+            == (other.not_empty, other.is_white, other.is_queen, other.current_player)
+    }
+
+    #[inline(always)]
+    fn get_draw_reason(&self) -> Option<DrawReason> {
+        Game::get_draw_reason(self)
+    }
+
+    #[inline(always)]
+    fn get_moves_with_takes(&self) -> MoveList {
+        Game::get_moves_with_takes(self)
+    }
+
+    #[inline(always)]
+    fn get_moves_without_takes(&self) -> MoveList {
+        Game::get_moves_without_takes(self)
+    }
+
+    #[inline(always)]
+    fn get_moves(&self) -> MoveList {
+        Game::get_moves(self)
+    }
+
+    #[inline(always)]
+    fn make_move(&mut self, move_to_make: Move) -> MoveUndo {
+        Game::make_move(self, move_to_make)
+    }
+
+    #[inline(always)]
+    fn unmake_move(&mut self, undo: MoveUndo) {
+        Game::unmake_move(self, undo)
+    }
+
+    #[inline(always)]
+    fn no_moves_wins(&self) -> bool {
+        self.ruleset.is_giveaway()
+    }
+
+    #[inline(always)]
+    fn make_capture_step(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        self.make_cutting_move(from, to)
+    }
+
+    #[inline(always)]
+    fn board_size(&self) -> usize {
+        8
+    }
+
+    #[inline(always)]
+    fn cell_index(&self, x: usize, y: usize) -> i8 {
+        conv_2d_to_1d(x, y)
+    }
+
+    #[inline(always)]
+    fn is_playable_cell(&self, x: usize, y: usize) -> bool {
+        (x + y) % 2 == self.ruleset.plays_on_dark_cells() as usize
+    }
+
+    #[inline(always)]
+    fn get_data(&self) -> Vec<Vec<Checker>> {
+        Game::get_data(self)
+    }
+
+    fn move_notation(&self, m: &Move) -> String {
+        m.to_string()
+    }
+}
+
 impl Debug for Game {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         Display::fmt(self, f)
@@ -0,0 +1,162 @@
+use crate::game::{Checker, DrawReason, Game, InternationalGame, Move, MoveError, MoveList, MoveUndo, Position, Ruleset, TurkishGame};
+use std::fmt::{Debug, Display, Formatter};
+
+// A position of any supported ruleset. Lets the UI and the bots work with all board sizes.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum AnyGame {
+    Standard(Game),
+    International(InternationalGame),
+    Turkish(TurkishGame),
+}
+
+// Undo record of the corresponding `AnyGame` variant.
+pub enum AnyMoveUndo {
+    Standard(MoveUndo),
+    International(InternationalGame),
+    Turkish(TurkishGame),
+}
+
+impl Default for AnyGame {
+    fn default() -> Self {
+        AnyGame::new(Ruleset::default())
+    }
+}
+
+impl AnyGame {
+    pub fn new(ruleset: Ruleset) -> AnyGame {
+        match ruleset {
+            Ruleset::International => AnyGame::International(InternationalGame::new()),
+            Ruleset::Turkish => AnyGame::Turkish(TurkishGame::new()),
+            _ => AnyGame::Standard(Game::new(ruleset)),
+        }
+    }
+}
+
+macro_rules! for_each_game {
+    ($self:expr, $game:ident => $body:expr) => {
+        match $self {
+            AnyGame::Standard($game) => $body,
+            AnyGame::International($game) => $body,
+            AnyGame::Turkish($game) => $body,
+        }
+    };
+}
+
+impl Display for AnyGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for_each_game!(self, game => Display::fmt(game, f))
+    }
+}
+
+impl Debug for AnyGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Position for AnyGame {
+    type Undo = AnyMoveUndo;
+
+    #[inline(always)]
+    fn current_player(&self) -> bool {
+        for_each_game!(self, game => game.current_player)
+    }
+
+    #[inline(always)]
+    fn change_player(&mut self) {
+        for_each_game!(self, game => Position::change_player(game))
+    }
+
+    #[inline(always)]
+    fn evaluate_for_me(&self) -> i32 {
+        for_each_game!(self, game => Position::evaluate_for_me(game))
+    }
+
+    #[inline(always)]
+    fn boring_moves_counter(&self) -> u8 {
+        for_each_game!(self, game => game.boring_moves_counter)
+    }
+
+    #[inline(always)]
+    fn is_same_position(&self, other: &AnyGame) -> bool {
+        match (self, other) {
+            (AnyGame::Standard(a), AnyGame::Standard(b)) => a.is_same_position(b),
+            (AnyGame::International(a), AnyGame::International(b)) => a.is_same_position(b),
+            (AnyGame::Turkish(a), AnyGame::Turkish(b)) => a.is_same_position(b),
+            _ => false,
+        }
+    }
+
+    #[inline(always)]
+    fn get_draw_reason(&self) -> Option<DrawReason> {
+        for_each_game!(self, game => Position::get_draw_reason(game))
+    }
+
+    #[inline(always)]
+    fn get_moves_with_takes(&self) -> MoveList {
+        for_each_game!(self, game => Position::get_moves_with_takes(game))
+    }
+
+    #[inline(always)]
+    fn get_moves_without_takes(&self) -> MoveList {
+        for_each_game!(self, game => Position::get_moves_without_takes(game))
+    }
+
+    #[inline(always)]
+    fn get_moves(&self) -> MoveList {
+        for_each_game!(self, game => Position::get_moves(game))
+    }
+
+    #[inline(always)]
+    fn make_move(&mut self, move_to_make: Move) -> AnyMoveUndo {
+        match self {
+            AnyGame::Standard(game) => AnyMoveUndo::Standard(game.make_move(move_to_make)),
+            AnyGame::International(game) => AnyMoveUndo::International(Position::make_move(game, move_to_make)),
+            AnyGame::Turkish(game) => AnyMoveUndo::Turkish(Position::make_move(game, move_to_make)),
+        }
+    }
+
+    #[inline(always)]
+    fn unmake_move(&mut self, undo: AnyMoveUndo) {
+        match (self, undo) {
+            (AnyGame::Standard(game), AnyMoveUndo::Standard(undo)) => game.unmake_move(undo),
+            (AnyGame::International(game), AnyMoveUndo::International(undo)) => Position::unmake_move(game, undo),
+            (AnyGame::Turkish(game), AnyMoveUndo::Turkish(undo)) => Position::unmake_move(game, undo),
+            _ => panic!("Undo record of another game type"),
+        }
+    }
+
+    #[inline(always)]
+    fn no_moves_wins(&self) -> bool {
+        for_each_game!(self, game => game.no_moves_wins())
+    }
+
+    #[inline(always)]
+    fn make_capture_step(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        for_each_game!(self, game => game.make_capture_step(from, to))
+    }
+
+    #[inline(always)]
+    fn board_size(&self) -> usize {
+        for_each_game!(self, game => game.board_size())
+    }
+
+    #[inline(always)]
+    fn cell_index(&self, x: usize, y: usize) -> i8 {
+        for_each_game!(self, game => game.cell_index(x, y))
+    }
+
+    #[inline(always)]
+    fn is_playable_cell(&self, x: usize, y: usize) -> bool {
+        for_each_game!(self, game => game.is_playable_cell(x, y))
+    }
+
+    #[inline(always)]
+    fn get_data(&self) -> Vec<Vec<Checker>> {
+        for_each_game!(self, game => Position::get_data(game))
+    }
+
+    fn move_notation(&self, m: &Move) -> String {
+        for_each_game!(self, game => game.move_notation(m))
+    }
+}
@@ -0,0 +1,102 @@
+use crate::game::notation::{cell_name, is_playable_cell};
+use crate::game::{Game, Ruleset};
+use crate::useful_functions::conv_2d_to_1d;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Why a board diagram couldn't be parsed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DiagramError {
+    // Not 8 rows of the board followed by the player to move.
+    InvalidFormat,
+    // A row isn't 8 cells of `.`, `w`, `b`, `W` and `B`, optionally with its number first.
+    InvalidRow(String),
+    // A checker on a cell checkers can't stand on in this ruleset.
+    NotPlayableCell(String),
+    // The last line isn't `White's move` or `Black's move`.
+    InvalidSideToMove(String),
+    // The ruleset is played by another type of position than `Game`.
+    UnsupportedRuleset(Ruleset),
+}
+
+impl Display for DiagramError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiagramError::InvalidFormat => write!(f, "expected 8 rows of the board and the player to move"),
+            DiagramError::InvalidRow(row) => write!(f, "invalid row: {row:?}"),
+            DiagramError::NotPlayableCell(cell) => write!(f, "checkers can't stand on {cell}"),
+            DiagramError::InvalidSideToMove(line) => write!(f, "invalid player to move: {line:?}"),
+            DiagramError::UnsupportedRuleset(ruleset) => write!(f, "{ruleset} positions can't be read from a diagram"),
+        }
+    }
+}
+
+impl std::error::Error for DiagramError {}
+
+impl Game {
+    // Parses the diagram printed by `Display`. The coordinates are optional and whitespace is ignored.
+    pub fn from_diagram(text: &str, ruleset: Ruleset) -> Result<Game, DiagramError> {
+        if !ruleset.is_played_by_game() {
+            return Err(DiagramError::UnsupportedRuleset(ruleset));
+        }
+        let mut lines: Vec<String> = text
+            .lines()
+            .map(|line| line.chars().filter(|c| !c.is_whitespace()).collect::<String>())
+            .filter(|line| !line.is_empty())
+            .collect();
+        if lines.first().is_some_and(|line| line == "abcdefgh") {
+            lines.remove(0);
+        }
+        let [rows @ .., side] = &lines[..] else {
+            return Err(DiagramError::InvalidFormat);
+        };
+        if rows.len() != 8 {
+            return Err(DiagramError::InvalidFormat);
+        }
+        let mut game = Game::new(ruleset);
+        game.current_player = match side.as_str() {
+            "White'smove" => true,
+            "Black'smove" => false,
+            _ => return Err(DiagramError::InvalidSideToMove(side.clone())),
+        };
+        game.not_empty = 0;
+        game.is_white = 0;
+        game.is_queen = 0;
+        for (y, row) in rows.iter().enumerate() {
+            let row_number = (b'8' - y as u8) as char;
+            let cells = row.strip_prefix(row_number).unwrap_or(row);
+            if cells.len() != 8 {
+                return Err(DiagramError::InvalidRow(row.clone()));
+            }
+            for (x, c) in cells.chars().enumerate() {
+                let (is_white, is_queen) = match c {
+                    '.' => continue,
+                    'w' => (true, false),
+                    'b' => (false, false),
+                    'W' => (true, true),
+                    'B' => (false, true),
+                    _ => return Err(DiagramError::InvalidRow(row.clone())),
+                };
+                let i = conv_2d_to_1d(x, y);
+                if !is_playable_cell(x, y, ruleset) {
+                    return Err(DiagramError::NotPlayableCell(cell_name(i)));
+                }
+                game.not_empty |= 1 << i;
+                game.is_white |= (is_white as u64) << i;
+                game.is_queen |= (is_queen as u64) << i;
+            }
+        }
+        game.eval_white = game.compute_eval_white();
+        game.update_key();
+        Ok(game)
+    }
+}
+
+// Parses a diagram of a Russian draughts position, see `Game::from_diagram`.
+impl FromStr for Game {
+    type Err = DiagramError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Game::from_diagram(text, Ruleset::default())
+    }
+}
@@ -0,0 +1,137 @@
+use crate::game::notation::{cell_to_square, is_playable_cell, parse_cell_name, parse_square_number, square_to_cell};
+use crate::game::{Game, Ruleset};
+use crate::useful_functions::conv_2d_to_1d;
+use std::fmt::{Display, Formatter};
+
+// Why a FEN string couldn't be parsed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FenError {
+    // The string isn't `<player>:<color><squares>:<color><squares>`.
+    InvalidFormat,
+    // The player to move isn't `W` or `B`.
+    InvalidSideToMove(String),
+    // A list of squares doesn't start with `W` or `B`, or both lists have the same color.
+    InvalidColor(String),
+    // Neither a number from 1 to 32, a range of numbers nor a cell like `c3`.
+    InvalidSquare(String),
+    // A cell checkers can't stand on in this ruleset.
+    NotPlayableSquare(String),
+    DuplicateSquare(String),
+    // A pawn on the last row, where it would have been promoted.
+    PawnOnPromotionRow(String),
+    // The ruleset is played by another type of position than `Game`.
+    UnsupportedRuleset(Ruleset),
+}
+
+impl Display for FenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FenError::InvalidFormat => write!(f, "expected <player>:<color><squares>:<color><squares>"),
+            FenError::InvalidSideToMove(side) => write!(f, "invalid player to move: {side:?}"),
+            FenError::InvalidColor(squares) => write!(f, "invalid color of the squares: {squares:?}"),
+            FenError::InvalidSquare(square) => write!(f, "invalid square: {square:?}"),
+            FenError::NotPlayableSquare(square) => write!(f, "checkers can't stand on {square}"),
+            FenError::DuplicateSquare(square) => write!(f, "square {square} is listed twice"),
+            FenError::PawnOnPromotionRow(square) => write!(f, "pawn on the promotion row: {square}"),
+            FenError::UnsupportedRuleset(ruleset) => write!(f, "{ruleset} positions can't be read from FEN"),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
+// Cells of a square number, a range of numbers like `1-12` or an algebraic cell like `c3`.
+fn parse_squares(name: &str, ruleset: Ruleset) -> Result<Vec<i8>, FenError> {
+    let invalid = || FenError::InvalidSquare(name.to_string());
+    if let Some((first, last)) = name.split_once('-') {
+        let (first, last) = (parse_square_number(first).ok_or_else(invalid)?, parse_square_number(last).ok_or_else(invalid)?);
+        if first > last {
+            return Err(invalid());
+        }
+        return Ok((first..=last).map(|square| square_to_cell(square, ruleset)).collect());
+    }
+    if let Some(square) = parse_square_number(name) {
+        return Ok(vec![square_to_cell(square, ruleset)]);
+    }
+    let (x, y) = parse_cell_name(name).ok_or_else(invalid)?;
+    if !is_playable_cell(x, y, ruleset) {
+        return Err(FenError::NotPlayableSquare(name.to_string()));
+    }
+    Ok(vec![conv_2d_to_1d(x, y)])
+}
+
+// Square numbers of the cells in the mask in increasing order, with the prefix.
+fn squares_of(mask: u64, prefix: &str) -> Vec<String> {
+    let mut squares: Vec<usize> = (0..64).filter(|i| mask >> i & 1 == 1).map(cell_to_square).collect();
+    squares.sort_unstable();
+    squares.into_iter().map(|square| format!("{prefix}{square}")).collect()
+}
+
+impl Game {
+    // Parses a PDN FEN position like `W:W21,22,K5:B1,2,3`: the player to move, then the white and the black
+    // checkers with kings marked by `K`. Squares are numbers from 1 to 32, ranges like `1-12` or cells like `c3`.
+    pub fn from_fen(fen: &str, ruleset: Ruleset) -> Result<Game, FenError> {
+        if !ruleset.is_played_by_game() {
+            return Err(FenError::UnsupportedRuleset(ruleset));
+        }
+        let sections: Vec<&str> = fen.trim().trim_end_matches('.').split(':').map(str::trim).collect();
+        let [side, first, second] = sections[..] else {
+            return Err(FenError::InvalidFormat);
+        };
+        let mut game = Game::new(ruleset);
+        game.current_player = match side {
+            "W" => true,
+            "B" => false,
+            _ => return Err(FenError::InvalidSideToMove(side.to_string())),
+        };
+        game.not_empty = 0;
+        game.is_white = 0;
+        game.is_queen = 0;
+        let mut colors_seen = [false; 2];
+        for section in [first, second] {
+            let is_white = match section.chars().next() {
+                Some('W') => true,
+                Some('B') => false,
+                _ => return Err(FenError::InvalidColor(section.to_string())),
+            };
+            if colors_seen[is_white as usize] {
+                return Err(FenError::InvalidColor(section.to_string()));
+            }
+            colors_seen[is_white as usize] = true;
+            for name in section[1..].split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                let (is_queen, squares) = match name.strip_prefix('K') {
+                    Some(squares) => (true, squares),
+                    None => (false, name),
+                };
+                for i in parse_squares(squares, ruleset)? {
+                    if !game.is_empty_cell(i) {
+                        return Err(FenError::DuplicateSquare(cell_to_square(i).to_string()));
+                    }
+                    if !is_queen && (is_white && i > 55 || !is_white && i < 8) {
+                        return Err(FenError::PawnOnPromotionRow(cell_to_square(i).to_string()));
+                    }
+                    game.not_empty |= 1 << i;
+                    game.is_white |= (is_white as u64) << i;
+                    game.is_queen |= (is_queen as u64) << i;
+                }
+            }
+        }
+        game.eval_white = game.compute_eval_white();
+        game.update_key();
+        Ok(game)
+    }
+
+    // The position in PDN FEN with square numbers, pawns before kings. Checkers captured by an unfinished
+    // capture are left out.
+    pub fn to_fen(&self) -> String {
+        let mut res = String::from(if self.current_player { "W" } else { "B" });
+        for is_white in [true, false] {
+            let checkers = self.not_empty & !self.captured & if is_white { self.is_white } else { !self.is_white };
+            let mut squares = squares_of(checkers & !self.is_queen, "");
+            squares.extend(squares_of(checkers & self.is_queen, "K"));
+            res.push_str(if is_white { ":W" } else { ":B" });
+            res.push_str(&squares.join(","));
+        }
+        res
+    }
+}
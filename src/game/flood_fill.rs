@@ -0,0 +1,86 @@
+use crate::useful_functions::{EXCLUDE_LEFT_COLUMN, EXCLUDE_RIGHT_COLUMN};
+
+// Moves of the flying kings computed with Kogge-Stone flood fills, without lookup tables.
+// `Game` uses them instead of the magic tables with the `flood_fill` feature.
+
+// The four diagonal directions as a shift of the cell number and the cells a step can end on.
+// A step right decreases the cell number by 1 and a step down by 8.
+#[derive(Clone, Copy)]
+enum Direction {
+    RightUp,
+    RightDown,
+    LeftUp,
+    LeftDown,
+}
+
+const DIRECTIONS: [Direction; 4] = [Direction::RightUp, Direction::RightDown, Direction::LeftUp, Direction::LeftDown];
+
+impl Direction {
+    // Cells that can be reached by a step, so that it doesn't wrap around the board.
+    #[inline(always)]
+    fn targets(self) -> u64 {
+        match self {
+            Direction::RightUp | Direction::RightDown => EXCLUDE_LEFT_COLUMN,
+            Direction::LeftUp | Direction::LeftDown => EXCLUDE_RIGHT_COLUMN,
+        }
+    }
+
+    // Moves every checker `steps` cells, without the wrap check.
+    #[inline(always)]
+    fn shift(self, mask: u64, steps: u32) -> u64 {
+        match self {
+            Direction::RightUp => mask << (7 * steps),
+            Direction::RightDown => mask >> (9 * steps),
+            Direction::LeftUp => mask << (9 * steps),
+            Direction::LeftDown => mask >> (7 * steps),
+        }
+    }
+
+    // Moves every checker one cell.
+    #[inline(always)]
+    fn step(self, mask: u64) -> u64 {
+        self.shift(mask, 1) & self.targets()
+    }
+
+    // Spreads the cells of `from` through `empty` cells, in 3 steps of 1, 2 and 4 cells.
+    #[inline(always)]
+    fn fill(self, mut from: u64, mut empty: u64) -> u64 {
+        empty &= self.targets();
+        from |= empty & self.shift(from, 1);
+        empty &= self.shift(empty, 1);
+        from |= empty & self.shift(from, 2);
+        empty &= self.shift(empty, 2);
+        from |= empty & self.shift(from, 4);
+        from
+    }
+
+    // The empty cells from the cell to the first of the checkers, and the checker.
+    #[inline(always)]
+    fn ray(self, from: u64, checkers: u64) -> u64 {
+        self.step(self.fill(from, !checkers))
+    }
+}
+
+// Cells a king on `from` can move to, before the first of the checkers in every direction.
+#[inline(always)]
+pub fn king_slide_targets(from: i8, checkers: u64) -> u64 {
+    let from = 1 << from;
+    let mut result = 0;
+    for dir in DIRECTIONS {
+        result |= dir.ray(from, checkers);
+    }
+    result & !checkers
+}
+
+// Cells a king on `from` can land on after capturing the first of the enemy checkers in some direction,
+// before the second one. Other checkers are not looked at.
+#[inline(always)]
+pub fn king_jump_targets(from: i8, enemies: u64) -> u64 {
+    let from = 1 << from;
+    let mut result = 0;
+    for dir in DIRECTIONS {
+        let captured = dir.ray(from, enemies) & enemies;
+        result |= dir.ray(captured, enemies);
+    }
+    result & !enemies
+}
@@ -0,0 +1,523 @@
+use crate::constants::*;
+use crate::game::{Checker, DrawReason, Move, MoveError, MoveList, Piece, Position};
+use crate::useful_functions::*;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+// Diagonal directions as (dx, dy). The first two are forward for white, the last two for black.
+const DIRECTIONS: [(i8, i8); 4] = [(1, -1), (-1, -1), (-1, 1), (1, 1)];
+
+// International draughts: 10x10 board, 20 checkers per player, flying kings, men capture backwards
+// and the capture taking the most pieces is mandatory.
+// Cell number is `99 - x - 10 * y`, so white (at the bottom) has the lowest numbers, like in `Game`.
+#[derive(Clone)]
+pub struct InternationalGame {
+    // Bitmasks for game field. If a cell is empty, all corresponding bits must be 0.
+    pub not_empty: u128,
+    pub is_white: u128,
+    pub is_queen: u128,
+    // Number of half-moves made only by kings since the last capture or man move.
+    pub boring_moves_counter: u8,
+    // Number of half-moves since the last capture or promotion, i.e. since the balance of forces changed.
+    pub balance_moves_counter: u8,
+    // `true` if white, `false` if black.
+    pub current_player: bool,
+    // Checkers captured during the current multi-jump. They stay on the board until the capture sequence ends.
+    pub captured: u128,
+}
+
+impl Default for InternationalGame {
+    fn default() -> Self {
+        InternationalGame::new()
+    }
+}
+
+impl InternationalGame {
+    pub fn new() -> InternationalGame {
+        let mut game = InternationalGame {
+            not_empty: 0,
+            is_white: 0,
+            is_queen: 0,
+            boring_moves_counter: 0,
+            balance_moves_counter: 0,
+            current_player: true,
+            captured: 0,
+        };
+        for y in 0..10 {
+            for x in 0..10 {
+                if (x + y) % 2 == 0 || (4..6).contains(&y) {
+                    continue;
+                }
+                let mask = 1 << conv_2d_to_1d_10x10(x, y);
+                game.not_empty |= mask;
+                if y >= 6 {
+                    game.is_white |= mask;
+                }
+            }
+        }
+        game
+    }
+
+    #[inline(always)]
+    pub fn is_empty_cell(&self, i: i8) -> bool {
+        (self.not_empty >> i) & 1 == 0
+    }
+
+    #[inline(always)]
+    pub fn is_white_checker(&self, i: i8) -> bool {
+        (self.is_white >> i) & 1 == 1
+    }
+
+    #[inline(always)]
+    pub fn is_queen_checker(&self, i: i8) -> bool {
+        (self.is_queen >> i) & 1 == 1
+    }
+
+    #[inline(always)]
+    fn get_piece(&self, i: i8) -> Piece {
+        if self.is_queen_checker(i) {
+            Piece::Queen
+        } else {
+            Piece::Pawn
+        }
+    }
+
+    #[inline(always)]
+    fn my_pieces(&self) -> u128 {
+        self.not_empty & if self.current_player { self.is_white } else { !self.is_white }
+    }
+
+    #[inline(always)]
+    fn enemy_pieces(&self) -> u128 {
+        self.not_empty & !self.my_pieces()
+    }
+
+    // Next cell in the given direction, if it is on the board.
+    #[inline(always)]
+    fn step(i: i8, (dx, dy): (i8, i8)) -> Option<i8> {
+        let (x, y) = conv_1d_to_2d_10x10(i);
+        let (x, y) = (x as i8 + dx, y as i8 + dy);
+        if (0..10).contains(&x) && (0..10).contains(&y) {
+            Some(conv_2d_to_1d_10x10(x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn is_promotion_cell(&self, i: i8) -> bool {
+        if self.current_player {
+            i >= 90
+        } else {
+            i < 10
+        }
+    }
+
+    // Material and advancement of the men, for white player.
+    pub fn evaluate(&self) -> i32 {
+        let mut eval = 0;
+        let mut cells = self.not_empty;
+        while cells != 0 {
+            let i = cells.trailing_zeros() as i8;
+            cells &= cells - 1;
+            let cost = if self.is_queen_checker(i) {
+                QUEEN_COST
+            } else if self.is_white_checker(i) {
+                PAWN_COST + (i / 10) as i32
+            } else {
+                PAWN_COST + (9 - i / 10) as i32
+            };
+            eval += if self.is_white_checker(i) { cost } else { -cost };
+        }
+        eval
+    }
+
+    // Checks the 25-move and the 16-move rules.
+    pub fn get_draw_reason(&self) -> Option<DrawReason> {
+        let white = self.not_empty & self.is_white;
+        let black = self.not_empty & !self.is_white;
+        let white_queens = white & self.is_queen;
+        let black_queens = black & self.is_queen;
+        if white_queens == 0 || black_queens == 0 {
+            return None;
+        }
+        if self.boring_moves_counter >= INTERNATIONAL_KING_MOVES_DRAW_LIMIT {
+            return Some(DrawReason::KingMoves);
+        }
+        // A lone king against at most three pieces including a king.
+        let strong = if white.count_ones() == 1 {
+            black
+        } else if black.count_ones() == 1 {
+            white
+        } else {
+            return None;
+        };
+        let limit = match strong.count_ones() {
+            3 => INTERNATIONAL_THREE_PIECES_DRAW_LIMIT,
+            1..=2 => INTERNATIONAL_TWO_PIECES_DRAW_LIMIT,
+            _ => return None,
+        };
+        if self.balance_moves_counter >= limit {
+            return Some(DrawReason::EndgameMoveLimit);
+        }
+        None
+    }
+
+    fn move_checker(&mut self, from: i8, to: i8) {
+        let from_mask = 1 << from;
+        let to_mask = 1 << to;
+        self.not_empty ^= from_mask ^ to_mask;
+        if self.is_white & from_mask != 0 {
+            self.is_white ^= from_mask ^ to_mask;
+        }
+        if self.is_queen & from_mask != 0 {
+            self.is_queen ^= from_mask ^ to_mask;
+        }
+    }
+
+    // Makes one jump of a capture sequence. The captured checker is only marked in `captured`,
+    // call `finish_capture` when the sequence is over.
+    pub fn make_cutting_move(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        let captured_cell = self.find_jumped_cell(from, to).ok_or(MoveError::IllegalJump(from, to))?;
+        self.make_jump(from, to, captured_cell);
+        Ok(())
+    }
+
+    // The checker taken by a jump of the current player, if the jump is possible: exactly one enemy checker,
+    // not captured yet, between the cells, and no farther than the next cell for men.
+    fn find_jumped_cell(&self, from: i8, to: i8) -> Option<i8> {
+        if !(0..100).contains(&from) || !(0..100).contains(&to) || self.is_empty_cell(from) || !self.is_empty_cell(to) {
+            return None;
+        }
+        if self.is_white_checker(from) != self.current_player || (self.captured >> from) & 1 == 1 {
+            return None;
+        }
+        let (fx, fy) = conv_1d_to_2d_10x10(from);
+        let (tx, ty) = conv_1d_to_2d_10x10(to);
+        let distance = fx.abs_diff(tx);
+        if distance != fy.abs_diff(ty) || distance > 2 && !self.is_queen_checker(from) {
+            return None;
+        }
+        let dir = ((tx as i8 - fx as i8).signum(), (ty as i8 - fy as i8).signum());
+        let mut jumped = None;
+        let mut curr_cell = Self::step(from, dir)?;
+        while curr_cell != to {
+            if !self.is_empty_cell(curr_cell) {
+                if jumped.is_some() {
+                    return None;
+                }
+                jumped = Some(curr_cell);
+            }
+            curr_cell = Self::step(curr_cell, dir)?;
+        }
+        jumped.filter(|&i| self.is_white_checker(i) != self.current_player && (self.captured >> i) & 1 == 0)
+    }
+
+    // `make_cutting_move` with the captured checker already known.
+    fn make_jump(&mut self, from: i8, to: i8, captured_cell: i8) {
+        self.move_checker(from, to);
+        self.captured |= 1 << captured_cell;
+    }
+
+    // Takes back `make_jump`.
+    fn unmake_jump(&mut self, from: i8, to: i8, captured_cell: i8) {
+        self.captured ^= 1 << captured_cell;
+        self.move_checker(to, from);
+    }
+
+    // Clears the cells with the checkers captured during the finished capture sequence.
+    // A man that ends the sequence on the last row is promoted.
+    pub fn finish_capture(&mut self, to: i8) {
+        let mask = !self.captured;
+        self.is_white &= mask;
+        self.not_empty &= mask;
+        self.is_queen &= mask;
+        self.captured = 0;
+        if self.is_promotion_cell(to) {
+            self.is_queen |= 1 << to;
+        }
+        self.boring_moves_counter = 0;
+        self.balance_moves_counter = 0;
+    }
+
+    pub fn make_move(&mut self, move_to_make: Move) {
+        let (a, b) = (move_to_make.from(), move_to_make.to());
+        if move_to_make.is_capture() {
+            let path = move_to_make.path();
+            for (i, &captured_cell) in move_to_make.captured().iter().enumerate() {
+                self.make_jump(path[i], path[i + 1], captured_cell);
+            }
+            self.finish_capture(b);
+        } else if self.is_queen_checker(a) {
+            self.boring_moves_counter = self.boring_moves_counter.saturating_add(1);
+            self.balance_moves_counter = self.balance_moves_counter.saturating_add(1);
+            self.move_checker(a, b);
+        } else {
+            self.boring_moves_counter = 0;
+            self.move_checker(a, b);
+            if self.is_promotion_cell(b) {
+                self.is_queen |= 1 << b;
+                self.balance_moves_counter = 0;
+            } else {
+                self.balance_moves_counter = self.balance_moves_counter.saturating_add(1);
+            }
+        }
+    }
+
+    // Extends the capture `current` with every jump from its last cell. Finished captures are added to `result`,
+    // returns `false` if there are no jumps.
+    // The jumps are made and taken back in place.
+    fn find_takes(&mut self, current: &mut Move, result: &mut MoveList) -> bool {
+        let from = current.to();
+        // Already captured checkers can't be taken again, so they are treated as blockers.
+        let enemy = self.enemy_pieces() & !self.captured;
+        let is_queen = self.is_queen_checker(from);
+        let mut has_jumps = false;
+        for dir in DIRECTIONS {
+            let mut curr_cell = from;
+            // Find the first non-empty cell on the diagonal. Men can only capture an adjacent checker.
+            let target = loop {
+                match Self::step(curr_cell, dir) {
+                    Some(i) if self.is_empty_cell(i) && is_queen => curr_cell = i,
+                    Some(i) if !self.is_empty_cell(i) => break Some(i),
+                    _ => break None,
+                }
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if enemy & (1 << target) == 0 {
+                continue;
+            }
+            let mut curr_cell = target;
+            while let Some(to) = Self::step(curr_cell, dir) {
+                if !self.is_empty_cell(to) {
+                    break;
+                }
+                has_jumps = true;
+                self.continue_capture(current, to, target, result);
+                if !is_queen {
+                    break;
+                }
+                curr_cell = to;
+            }
+        }
+        has_jumps
+    }
+
+    // Jumps from the last cell of `current` to `to` and looks for the next jumps. The jump is taken back after.
+    fn continue_capture(&mut self, current: &mut Move, to: i8, captured_cell: i8, result: &mut MoveList) {
+        let from = current.to();
+        self.make_jump(from, to, captured_cell);
+        current.push_capture(to, captured_cell);
+        // No jumps from here, so the capture ends on this cell.
+        if !self.find_takes(current, result) {
+            let mut res = *current;
+            res.promotion = res.piece == Piece::Pawn && self.is_promotion_cell(to);
+            result.push_majority_capture(res);
+        }
+        current.pop_capture();
+        self.unmake_jump(from, to, captured_cell);
+    }
+
+    // Only the sequences capturing the most checkers are allowed.
+    pub fn get_moves_with_takes(&self) -> MoveList {
+        let mut takes = MoveList::new();
+        // The capture search makes the jumps on its own copy.
+        let mut game = self.clone();
+        let mut cells = self.my_pieces();
+        while cells != 0 {
+            let i = cells.trailing_zeros() as i8;
+            cells &= cells - 1;
+            let mut current = Move::new_capture(i, self.get_piece(i));
+            game.find_takes(&mut current, &mut takes);
+        }
+        takes
+    }
+
+    pub fn get_moves_without_takes(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        let mut cells = self.my_pieces();
+        let directions = if self.current_player {
+            &DIRECTIONS[..2]
+        } else {
+            &DIRECTIONS[2..]
+        };
+        while cells != 0 {
+            let from = cells.trailing_zeros() as i8;
+            cells &= cells - 1;
+            if self.is_queen_checker(from) {
+                for &dir in &DIRECTIONS {
+                    let mut curr_cell = from;
+                    while let Some(to) = Self::step(curr_cell, dir).filter(|&i| self.is_empty_cell(i)) {
+                        moves.push(Move::new_simple(from, to, Piece::Queen, false));
+                        curr_cell = to;
+                    }
+                }
+            } else {
+                for &dir in directions {
+                    if let Some(to) = Self::step(from, dir).filter(|&i| self.is_empty_cell(i)) {
+                        moves.push(Move::new_simple(from, to, Piece::Pawn, self.is_promotion_cell(to)));
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_data(&self) -> Vec<Vec<Checker>> {
+        (0..10)
+            .map(|y| {
+                (0..10)
+                    .map(|x| {
+                        let i = conv_2d_to_1d_10x10(x, y);
+                        match (self.is_empty_cell(i), self.is_white_checker(i), self.is_queen_checker(i)) {
+                            (true, _, _) => Checker::Empty,
+                            (false, true, false) => Checker::White,
+                            (false, true, true) => Checker::WhiteQueen,
+                            (false, false, false) => Checker::Black,
+                            (false, false, true) => Checker::BlackQueen,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Hash for InternationalGame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u128(self.not_empty);
+        state.write_u128(self.is_white);
+        state.write_u128(self.is_queen);
+        state.write_u8(self.current_player as u8);
+        state.write_u8(self.boring_moves_counter);
+    }
+}
+
+impl PartialEq for InternationalGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_same_position(other) && self.boring_moves_counter == other.boring_moves_counter
+    }
+}
+
+impl Eq for InternationalGame {}
+
+impl Display for InternationalGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "   a b c d e f g h i j\n{}\n{}'s move",
+            self.get_data()
+                .iter()
+                .map(|row| row
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" "))
+                .enumerate()
+                .map(|(i, x)| format!("{:>2} {}", 10 - i, x))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            if self.current_player { "White" } else { "Black" }
+        )
+    }
+}
+
+impl Debug for InternationalGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Position for InternationalGame {
+    // The position is small and has no heap data, so the whole previous position is the undo record.
+    type Undo = InternationalGame;
+
+    #[inline(always)]
+    fn current_player(&self) -> bool {
+        self.current_player
+    }
+
+    #[inline(always)]
+    fn change_player(&mut self) {
+        self.current_player = !self.current_player;
+    }
+
+    #[inline(always)]
+    fn evaluate_for_me(&self) -> i32 {
+        self.evaluate() * (self.current_player as i32 * 2 - 1)
+    }
+
+    #[inline(always)]
+    fn boring_moves_counter(&self) -> u8 {
+        self.boring_moves_counter
+    }
+
+    #[inline(always)]
+    fn is_same_position(&self, other: &InternationalGame) -> bool {
+        (self.not_empty, self.is_white, self.is_queen, self.current_player)
+            == (other.not_empty, other.is_white, other.is_queen, other.current_player)
+    }
+
+    #[inline(always)]
+    fn get_draw_reason(&self) -> Option<DrawReason> {
+        InternationalGame::get_draw_reason(self)
+    }
+
+    #[inline(always)]
+    fn get_moves_with_takes(&self) -> MoveList {
+        InternationalGame::get_moves_with_takes(self)
+    }
+
+    #[inline(always)]
+    fn get_moves_without_takes(&self) -> MoveList {
+        InternationalGame::get_moves_without_takes(self)
+    }
+
+    #[inline(always)]
+    fn make_move(&mut self, move_to_make: Move) -> InternationalGame {
+        let undo = self.clone();
+        InternationalGame::make_move(self, move_to_make);
+        undo
+    }
+
+    #[inline(always)]
+    fn unmake_move(&mut self, undo: InternationalGame) {
+        *self = undo;
+    }
+
+    #[inline(always)]
+    fn make_capture_step(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        self.make_cutting_move(from, to)
+    }
+
+    #[inline(always)]
+    fn board_size(&self) -> usize {
+        10
+    }
+
+    #[inline(always)]
+    fn cell_index(&self, x: usize, y: usize) -> i8 {
+        conv_2d_to_1d_10x10(x, y)
+    }
+
+    #[inline(always)]
+    fn get_data(&self) -> Vec<Vec<Checker>> {
+        InternationalGame::get_data(self)
+    }
+
+    // Square numbers from 1 to 50 along the rows, from the top left corner: `32-28`, `28x19x10`.
+    fn move_notation(&self, m: &Move) -> String {
+        let separator = if m.is_capture() { "x" } else { "-" };
+        let squares: Vec<String> = m
+            .path()
+            .iter()
+            .map(|&i| {
+                let (x, y) = conv_1d_to_2d_10x10(i);
+                (y * 5 + x / 2 + 1).to_string()
+            })
+            .collect();
+        squares.join(separator)
+    }
+}
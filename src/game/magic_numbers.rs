@@ -1,102 +1,243 @@
-use std::{alloc, slice};
+use crate::useful_functions::{conv_1d_to_2d, conv_2d_to_1d, DIAGONALS};
 use lazy_static::lazy_static;
-use crate::constants::{MOVES_WITHOUT_CAPTURES_COMPRESSED, MOVES_WITH_CAPTURES_COMPRESSED};
+
+// Lookup tables of the flying kings. A set of checkers on the diagonals of a cell is turned into an index
+// by multiplying it by the magic number of the cell and keeping the highest `MAGIC_INDEX_BITS` bits.
+// Every cell has its own part of the tables, starting at `MAGIC_OFFSETS`. The tables are built on first use
+// from `DIAGONALS`, `MAGIC_NUMBERS` and `MAGIC_INDEX_BITS`, `src/bin/magic_search.rs` finds new magic numbers.
 
 lazy_static! {
-    pub static ref MOVES_WITHOUT_CAPTURES: &'static [u64] = {
-        let result = unsafe {
-            alloc::alloc(alloc::Layout::array::<u8>(DECOMPRESSED_SIZE).unwrap())
-        };
-        let slice = unsafe {
-            slice::from_raw_parts_mut(result, DECOMPRESSED_SIZE)
-        };
-        snap::raw::Decoder::new().decompress(MOVES_WITHOUT_CAPTURES_COMPRESSED, slice).unwrap();
-        unsafe {
-            slice::from_raw_parts(result as *const u64, MAX_POSITION_MAGIC_INDEX * 64)
+    // Cells a king can move to when the given checkers block its diagonals.
+    pub static ref MOVES_WITHOUT_CAPTURES: Vec<u64> = build_table(slide_targets);
+
+    // Cells a king can land on after capturing one of the given enemy checkers, not looking at its own checkers.
+    pub static ref MOVES_WITH_CAPTURES: Vec<u64> = build_table(jump_targets);
+}
+
+// Start of the entries of every cell, the last one is the size of a table.
+pub const MAGIC_OFFSETS: [usize; 65] = magic_offsets();
+pub const MAGIC_TABLE_SIZE: usize = MAGIC_OFFSETS[64];
+
+const fn magic_offsets() -> [usize; 65] {
+    let mut offsets = [0; 65];
+    let mut i = 0;
+    while i < 64 {
+        let bits = MAGIC_INDEX_BITS[i];
+        offsets[i + 1] = offsets[i] + if bits == 0 { 0 } else { 1 << bits };
+        i += 1;
+    }
+    offsets
+}
+
+const DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+// Cells from the cell to the board edge in the direction.
+fn ray(from: i8, (dx, dy): (i8, i8)) -> impl Iterator<Item = i8> {
+    let (x, y) = conv_1d_to_2d(from);
+    (1..8)
+        .map(move |k| (x as i8 + dx * k, y as i8 + dy * k))
+        .take_while(|&(x, y)| (0..8).contains(&x) && (0..8).contains(&y))
+        .map(|(x, y)| conv_2d_to_1d(x as usize, y as usize))
+}
+
+// Reference for `MOVES_WITHOUT_CAPTURES`: the empty cells before the first blocker in every direction.
+pub fn slide_targets(from: i8, blockers: u64) -> u64 {
+    let mut result = 0;
+    for dir in DIRECTIONS {
+        for i in ray(from, dir) {
+            if blockers >> i & 1 == 1 {
+                break;
+            }
+            result |= 1 << i;
         }
-    };
-
-    pub static ref MOVES_WITH_CAPTURES: &'static [u64] = {
-        let result = unsafe {
-            alloc::alloc(alloc::Layout::array::<u8>(DECOMPRESSED_SIZE).unwrap())
-        };
-        let slice = unsafe {
-            slice::from_raw_parts_mut(result, DECOMPRESSED_SIZE)
-        };
-        snap::raw::Decoder::new().decompress(MOVES_WITH_CAPTURES_COMPRESSED, slice).unwrap();
-        unsafe {
-            slice::from_raw_parts(result as *const u64, MAX_POSITION_MAGIC_INDEX * 64)
+    }
+    result
+}
+
+// Reference for `MOVES_WITH_CAPTURES`: the cells between the first and the second enemy checker in every direction.
+pub fn jump_targets(from: i8, enemies: u64) -> u64 {
+    let mut result = 0;
+    for dir in DIRECTIONS {
+        let mut has_taken = false;
+        for i in ray(from, dir) {
+            if enemies >> i & 1 == 1 {
+                if has_taken {
+                    break;
+                }
+                has_taken = true;
+            } else if has_taken {
+                result |= 1 << i;
+            }
         }
-    };
+    }
+    result
+}
+
+// Every set of checkers on the diagonals of the cell.
+pub fn diagonal_subsets(from: i8) -> impl Iterator<Item = u64> {
+    let diagonal = DIAGONALS[from as usize];
+    let mut subset = Some(0u64);
+    std::iter::from_fn(move || {
+        let result = subset?;
+        let next = result.wrapping_sub(diagonal) & diagonal;
+        subset = (next != 0).then_some(next);
+        Some(result)
+    })
+}
+
+// Index of the set of checkers on the diagonals of the cell in the tables.
+#[inline(always)]
+pub fn magic_index(from: i8, checkers: u64) -> usize {
+    let from = from as usize;
+    MAGIC_OFFSETS[from] + cell_magic_index(checkers, MAGIC_NUMBERS[from], MAGIC_INDEX_BITS[from])
 }
 
-pub const MAX_POSITION_MAGIC_INDEX: usize = 65536;
-pub const MAGIC_RSHIFT: i32 = 48;
-const DECOMPRESSED_SIZE: usize = MAX_POSITION_MAGIC_INDEX * 8 * 64;
+// Index among the entries of one cell.
+#[inline(always)]
+fn cell_magic_index(checkers: u64, magic_number: u64, index_bits: u32) -> usize {
+    (checkers.wrapping_mul(magic_number) >> (64 - index_bits)) as usize
+}
+
+// Checks magic numbers of a cell. Keeps the lookups of every set of checkers between the checks,
+// so many numbers can be tried quickly.
+pub struct MagicChecker {
+    // Sets of checkers with the cells of both tables.
+    cases: Vec<(u64, u64, u64)>,
+    // Table entries, valid if their check number is the current one.
+    entries: Vec<(u32, u64, u64)>,
+    check_number: u32,
+}
+
+impl MagicChecker {
+    pub fn new(from: i8) -> MagicChecker {
+        MagicChecker {
+            cases: diagonal_subsets(from)
+                .map(|checkers| (checkers, slide_targets(from, checkers), jump_targets(from, checkers)))
+                .collect(),
+            entries: Vec::new(),
+            check_number: 0,
+        }
+    }
+
+    // No smaller index can tell apart the different entries of the cell.
+    pub fn min_index_bits(&self) -> u32 {
+        let mut entries: Vec<(u64, u64)> = self.cases.iter().map(|&(_, slide, jump)| (slide, jump)).collect();
+        entries.sort_unstable();
+        entries.dedup();
+        entries.len().next_power_of_two().trailing_zeros().max(1)
+    }
+
+    // A magic number is usable with the index size if the sets of checkers sharing an index
+    // have the same cells in both tables.
+    pub fn is_valid(&mut self, magic_number: u64, index_bits: u32) -> bool {
+        if !(1..=32).contains(&index_bits) {
+            return false;
+        }
+        if self.entries.len() < 1 << index_bits {
+            self.entries.resize(1 << index_bits, (0, 0, 0));
+        }
+        self.check_number += 1;
+        for &(checkers, slide, jump) in &self.cases {
+            let entry = &mut self.entries[cell_magic_index(checkers, magic_number, index_bits)];
+            if entry.0 != self.check_number {
+                *entry = (self.check_number, slide, jump);
+            } else if (entry.1, entry.2) != (slide, jump) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Cells without a magic number (the light ones) have no entries, unused entries are 0.
+fn build_table(targets: fn(i8, u64) -> u64) -> Vec<u64> {
+    let mut table = vec![0; MAGIC_TABLE_SIZE];
+    for from in 0..64 {
+        if MAGIC_INDEX_BITS[from as usize] == 0 {
+            continue;
+        }
+        for checkers in diagonal_subsets(from) {
+            table[magic_index(from, checkers)] = targets(from, checkers);
+        }
+    }
+    table
+}
 
 pub const MAGIC_NUMBERS: [u64; 64] = [
     0,
-    4143770916557949827,
+    2262812186448128,
     0,
-    5632582401447071962,
+    74315167370316416,
     0,
-    2953914551181118847,
+    6917600498583371904,
     0,
-    6562325409673774120,
-    9585450094518732674,
+    4538938652377090,
+    1153554913515602961,
     0,
-    16177932326718848195,
+    576463503246788864,
     0,
-    5438547638821680427,
+    864691680896745472,
     0,
-    1316988157189021112,
+    738608102907060226,
     0,
     0,
-    11236089243641839446,
+    9404079659107292033,
     0,
-    8373016117065810887,
+    4684870096489816066,
     0,
-    18298605313844674498,
+    36063985721671712,
     0,
-    7774925274806327043,
-    11552719122198531437,
+    9299950824862025760,
+    144572584980328466,
     0,
-    16355948053622440450,
+    1162509246034640899,
     0,
-    13835257906240287104,
+    1161928841438955584,
     0,
-    7904793738053181469,
+    144227613679288336,
     0,
     0,
-    16221762135114142392,
+    4641029853469085698,
     0,
-    18131146181627609087,
+    4503737603719177,
     0,
-    2238605914536407042,
+    1765983899621589000,
     0,
-    10182201213894037784,
-    17492436739168537486,
+    576496076270469697,
+    5206198558004400144,
     0,
-    8626459604100937254,
+    324822191910690884,
     0,
-    15234342964417006010,
+    657808120638079489,
     0,
-    15276907987282906584,
+    6919783028692878872,
     0,
     0,
-    13441332562300502496,
+    148621193960756368,
     0,
-    17714124635424953016,
+    292884643643129896,
     0,
-    3760132481320223818,
+    4652782198527754329,
     0,
-    8091113704835661926,
-    16791105621719238935,
+    865536945030332674,
+    18032557900038400,
     0,
-    126634105886478339,
+    216878879099453952,
     0,
-    8485869665191866413,
+    324823772866021889,
     0,
-    8276781792258883840,
+    2254070845276288,
     0,
 ];
+
+// Bits of the index of every cell, 0 for the light cells.
+pub const MAGIC_INDEX_BITS: [u32; 64] = [
+    0, 7, 0, 7, 0, 7, 0, 7,
+    7, 0, 9, 0, 9, 0, 9, 0,
+    0, 9, 0, 11, 0, 11, 0, 7,
+    7, 0, 11, 0, 13, 0, 9, 0,
+    0, 9, 0, 13, 0, 11, 0, 7,
+    7, 0, 11, 0, 11, 0, 9, 0,
+    0, 9, 0, 9, 0, 9, 0, 7,
+    7, 0, 7, 0, 7, 0, 7, 0,
+];
@@ -0,0 +1,150 @@
+use crate::constants::MAX_MOVES;
+use crate::game::Move;
+use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+
+// Fixed-capacity list of moves stored inline, so generating moves doesn't allocate.
+#[derive(Clone)]
+pub struct MoveList {
+    moves: [MaybeUninit<Move>; MAX_MOVES],
+    len: usize,
+}
+
+impl MoveList {
+    #[inline(always)]
+    pub fn new() -> MoveList {
+        MoveList {
+            moves: [const { MaybeUninit::uninit() }; MAX_MOVES],
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(&mut self, m: Move) {
+        assert!(self.len < MAX_MOVES, "Too many moves in a position");
+        self.moves[self.len] = MaybeUninit::new(m);
+        self.len += 1;
+    }
+
+    // Adds a capture when only the captures taking the most checkers are allowed. Captures shorter than
+    // the ones in the list are dropped, and a longer one replaces them all, so the list stays small.
+    #[inline(always)]
+    pub fn push_majority_capture(&mut self, m: Move) {
+        if let Some(first) = self.first() {
+            let (len, max_len) = (m.captured().len(), first.captured().len());
+            if len < max_len {
+                return;
+            }
+            if len > max_len {
+                self.clear();
+            }
+        }
+        self.push(m);
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    // Keeps only the moves for which `f` returns `true`, preserving their order.
+    pub fn retain(&mut self, mut f: impl FnMut(&Move) -> bool) {
+        let mut new_len = 0;
+        for i in 0..self.len {
+            let m = self[i];
+            if f(&m) {
+                self.moves[new_len] = MaybeUninit::new(m);
+                new_len += 1;
+            }
+        }
+        self.len = new_len;
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        MoveList::new()
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Move];
+
+    #[inline(always)]
+    fn deref(&self) -> &[Move] {
+        // The first `len` moves are initialized.
+        unsafe { slice::from_raw_parts(self.moves.as_ptr() as *const Move, self.len) }
+    }
+}
+
+impl DerefMut for MoveList {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [Move] {
+        unsafe { slice::from_raw_parts_mut(self.moves.as_mut_ptr() as *mut Move, self.len) }
+    }
+}
+
+impl Debug for MoveList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<Move> for MoveList {
+    fn extend<T: IntoIterator<Item = Move>>(&mut self, iter: T) {
+        for m in iter {
+            self.push(m);
+        }
+    }
+}
+
+impl FromIterator<Move> for MoveList {
+    fn from_iter<T: IntoIterator<Item = Move>>(iter: T) -> Self {
+        let mut res = MoveList::new();
+        res.extend(iter);
+        res
+    }
+}
+
+pub struct IntoIter {
+    list: MoveList,
+    pos: usize,
+}
+
+impl Iterator for IntoIter {
+    type Item = Move;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Move> {
+        let res = self.list.get(self.pos).copied();
+        self.pos += 1;
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len().saturating_sub(self.pos);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for MoveList {
+    type Item = Move;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter { list: self, pos: 0 }
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> slice::Iter<'a, Move> {
+        self.iter()
+    }
+}
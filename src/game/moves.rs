@@ -0,0 +1,160 @@
+use crate::constants::MAX_CAPTURES;
+use crate::game::notation::{cell_name, cell_to_square};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Piece {
+    Pawn,
+    Queen,
+}
+
+// A move with everything known about it when it was generated. Captures store every landing cell
+// and the captured checkers in the order they were taken.
+#[derive(Clone, Copy)]
+pub struct Move {
+    // The start cell, then every cell the checker lands on.
+    path: [i8; MAX_CAPTURES + 1],
+    path_len: u8,
+    captured: [i8; MAX_CAPTURES],
+    captured_len: u8,
+    // The moving checker before the move.
+    pub piece: Piece,
+    // A pawn becomes a king as a result of the move.
+    pub promotion: bool,
+}
+
+impl Move {
+    pub fn new_simple(from: i8, to: i8, piece: Piece, promotion: bool) -> Move {
+        let mut res = Move::new_capture(from, piece);
+        res.path[1] = to;
+        res.path_len = 2;
+        res.promotion = promotion;
+        res
+    }
+
+    // A capture without jumps yet, extended by `push_capture`.
+    pub fn new_capture(from: i8, piece: Piece) -> Move {
+        let mut path = [0; MAX_CAPTURES + 1];
+        path[0] = from;
+        Move {
+            path,
+            path_len: 1,
+            captured: [0; MAX_CAPTURES],
+            captured_len: 0,
+            piece,
+            promotion: false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn push_capture(&mut self, to: i8, captured_cell: i8) {
+        self.path[self.path_len as usize] = to;
+        self.captured[self.captured_len as usize] = captured_cell;
+        self.path_len += 1;
+        self.captured_len += 1;
+    }
+
+    #[inline(always)]
+    pub fn pop_capture(&mut self) {
+        self.path_len -= 1;
+        self.captured_len -= 1;
+    }
+
+    #[inline(always)]
+    pub fn from(&self) -> i8 {
+        self.path[0]
+    }
+
+    #[inline(always)]
+    pub fn to(&self) -> i8 {
+        self.path[self.path_len as usize - 1]
+    }
+
+    #[inline(always)]
+    pub fn path(&self) -> &[i8] {
+        &self.path[..self.path_len as usize]
+    }
+
+    #[inline(always)]
+    pub fn captured(&self) -> &[i8] {
+        &self.captured[..self.captured_len as usize]
+    }
+
+    #[inline(always)]
+    pub fn is_capture(&self) -> bool {
+        self.captured_len != 0
+    }
+
+    pub fn as_vec(&self) -> Vec<i8> {
+        self.path().to_vec()
+    }
+}
+
+// Cells after `path_len` and `captured_len` are left over from other capture branches, only the used ones are compared.
+impl PartialEq for Move {
+    fn eq(&self, other: &Self) -> bool {
+        self.path() == other.path()
+            && self.captured() == other.captured()
+            && self.piece == other.piece
+            && self.promotion == other.promotion
+    }
+}
+
+impl Eq for Move {}
+
+impl Hash for Move {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path().hash(state);
+        self.captured().hash(state);
+        self.piece.hash(state);
+        self.promotion.hash(state);
+    }
+}
+
+impl From<Move> for Vec<i8> {
+    fn from(value: Move) -> Self {
+        value.as_vec()
+    }
+}
+
+// Russian algebraic notation of a move of the 8x8 `Game`: `c3-d4`, captures with every landing cell: `c3:e5:c7`.
+// The alternate form `{:#}` uses square numbers from 1 to 32: `22-17`, `22x13x6`, and only fits the diagonal boards.
+// Moves of the 10x10 board have no cell names here and fail to format, `Position::move_notation` writes them.
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.path().iter().any(|&i| i >= 64) {
+            return Err(std::fmt::Error);
+        }
+        let numeric = f.alternate();
+        let separator = match (self.is_capture(), numeric) {
+            (false, _) => "-",
+            (true, true) => "x",
+            (true, false) => ":",
+        };
+        for (k, &i) in self.path().iter().enumerate() {
+            if k > 0 {
+                f.write_str(separator)?;
+            }
+            if numeric {
+                write!(f, "{}", cell_to_square(i))?;
+            } else {
+                f.write_str(&cell_name(i))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {:?}", self.piece, self.path())?;
+        if self.is_capture() {
+            write!(f, " x{:?}", self.captured())?;
+        }
+        if self.promotion {
+            write!(f, " promotion")?;
+        }
+        Ok(())
+    }
+}
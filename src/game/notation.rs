@@ -0,0 +1,142 @@
+use crate::game::{Game, Move, Ruleset};
+use crate::useful_functions::{conv_1d_to_2d, conv_2d_to_1d};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Square names of the 8x8 board used by FEN, PDN and the moves notation. Squares are numbered from 1 to 32 along the rows,
+// from the top left corner of the board, or named by algebraic cells like `c3`.
+
+#[inline(always)]
+pub(crate) fn square_to_cell(square: usize, ruleset: Ruleset) -> i8 {
+    let y = (square - 1) / 4;
+    let x = (square - 1) % 4 * 2 + (y + ruleset.plays_on_dark_cells() as usize) % 2;
+    conv_2d_to_1d(x, y)
+}
+
+#[inline(always)]
+pub(crate) fn cell_to_square(i: i8) -> usize {
+    let (x, y) = conv_1d_to_2d(i);
+    y * 4 + x / 2 + 1
+}
+
+pub(crate) fn cell_name(i: i8) -> String {
+    let (x, y) = conv_1d_to_2d(i);
+    format!("{}{}", (b'a' + x as u8) as char, 8 - y)
+}
+
+pub(crate) fn parse_square_number(name: &str) -> Option<usize> {
+    name.parse().ok().filter(|square| (1..=32).contains(square))
+}
+
+// Board coordinates of an algebraic cell, playable or not.
+pub(crate) fn parse_cell_name(name: &str) -> Option<(usize, usize)> {
+    let &[column @ b'a'..=b'h', row @ b'1'..=b'8'] = name.as_bytes() else {
+        return None;
+    };
+    Some(((column - b'a') as usize, (b'8' - row) as usize))
+}
+
+#[inline(always)]
+pub(crate) fn is_playable_cell(x: usize, y: usize, ruleset: Ruleset) -> bool {
+    (x + y) % 2 == ruleset.plays_on_dark_cells() as usize
+}
+
+// One square of a written move.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Square {
+    Number(usize),
+    // Board coordinates from the top left corner.
+    Cell(usize, usize),
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Square::Number(square) => write!(f, "{square}"),
+            Square::Cell(x, y) => write!(f, "{}", cell_name(conv_2d_to_1d(x, y))),
+        }
+    }
+}
+
+// Why a written move couldn't be turned into a legal move.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MoveParseError {
+    // Not two or more squares separated by `-`, `:` or `x`.
+    InvalidSyntax(String),
+    // A cell checkers can't stand on in this ruleset.
+    NotPlayableSquare(String),
+    IllegalMove(String),
+    // A short form of a capture matching several legal captures, which are listed.
+    AmbiguousMove(Vec<Move>),
+}
+
+impl Display for MoveParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveParseError::InvalidSyntax(text) => write!(f, "not a move: {text:?}"),
+            MoveParseError::NotPlayableSquare(square) => write!(f, "checkers can't stand on {square}"),
+            MoveParseError::IllegalMove(text) => write!(f, "illegal move: {text}"),
+            MoveParseError::AmbiguousMove(moves) => {
+                let moves: Vec<String> = moves.iter().map(Move::to_string).collect();
+                write!(f, "ambiguous move, it can be {}", moves.join(" or "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoveParseError {}
+
+// A move as it is written: `c3-d4`, `c3:e5:c7`, `22-17` or `22x13`. Captures can be written with only some
+// of their landing cells, e.g. `c3:c7`, if that leaves one legal capture. The separators aren't checked.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MoveNotation {
+    text: String,
+    squares: Vec<Square>,
+}
+
+impl FromStr for MoveNotation {
+    type Err = MoveParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let squares = text
+            .split(['-', ':', 'x'])
+            .map(|name| match parse_square_number(name) {
+                Some(square) => Some(Square::Number(square)),
+                None => parse_cell_name(name).map(|(x, y)| Square::Cell(x, y)),
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|squares| squares.len() >= 2)
+            .ok_or_else(|| MoveParseError::InvalidSyntax(text.to_string()))?;
+        Ok(MoveNotation { text: text.to_string(), squares })
+    }
+}
+
+impl MoveNotation {
+    // Finds the legal move of the position with these squares.
+    pub fn resolve(&self, game: &Game) -> Result<Move, MoveParseError> {
+        let cells = self.squares.iter().map(|&square| match square {
+            Square::Number(square) => Ok(square_to_cell(square, game.ruleset)),
+            Square::Cell(x, y) if is_playable_cell(x, y, game.ruleset) => Ok(conv_2d_to_1d(x, y)),
+            Square::Cell(..) => Err(MoveParseError::NotPlayableSquare(square.to_string())),
+        }).collect::<Result<Vec<i8>, _>>()?;
+        let (from, to) = (cells[0], cells[cells.len() - 1]);
+        let mut found: Vec<Move> = game.get_moves().into_iter().filter(|m| {
+            // The other squares have to be landing cells of the capture, in the same order.
+            let mut path = m.path()[1..m.path().len() - 1].iter();
+            m.from() == from && m.to() == to && cells[1..cells.len() - 1].iter().all(|cell| path.any(|i| i == cell))
+        }).collect();
+        match found.len() {
+            0 => Err(MoveParseError::IllegalMove(self.text.clone())),
+            1 => Ok(found.pop().unwrap()),
+            _ => Err(MoveParseError::AmbiguousMove(found)),
+        }
+    }
+}
+
+impl Game {
+    // Parses a move in algebraic or numeric notation and finds it among the legal moves.
+    pub fn parse_move(&self, text: &str) -> Result<Move, MoveParseError> {
+        text.parse::<MoveNotation>()?.resolve(self)
+    }
+}
@@ -0,0 +1,396 @@
+use crate::game::{FenError, Game, Move, MoveParseError, Ruleset};
+use std::fmt::{Display, Formatter};
+
+// Result of a game as written in PDN.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PdnResult {
+    WhiteWon,
+    BlackWon,
+    Draw,
+    // The game isn't finished or the result isn't known.
+    #[default]
+    Unknown,
+}
+
+impl PdnResult {
+    // Draughts results like `2-0`, and the chess style ones like `1-0` used in some files.
+    fn parse(text: &str) -> Option<PdnResult> {
+        Some(match text {
+            "2-0" | "1-0" => PdnResult::WhiteWon,
+            "0-2" | "0-1" => PdnResult::BlackWon,
+            "1-1" | "1/2-1/2" => PdnResult::Draw,
+            "*" => PdnResult::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for PdnResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            PdnResult::WhiteWon => "2-0",
+            PdnResult::BlackWon => "0-2",
+            PdnResult::Draw => "1-1",
+            PdnResult::Unknown => "*",
+        })
+    }
+}
+
+// Why a PDN text couldn't be read.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PdnError {
+    // A tag pair isn't `[Name "value"]`.
+    InvalidHeader(String),
+    // A `GameType` that isn't played on the 8x8 board by `Game`.
+    UnsupportedGameType(String),
+    InvalidFen(FenError),
+    // Text in the moves that is neither a move, a move number, a comment nor a result.
+    InvalidToken(String),
+    // A move that isn't legal in the position.
+    IllegalMove(String),
+    // A short form of a capture that matches several legal captures.
+    AmbiguousMove(String),
+}
+
+impl Display for PdnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PdnError::InvalidHeader(header) => write!(f, "invalid header: {header:?}"),
+            PdnError::UnsupportedGameType(game_type) => write!(f, "unsupported game type: {game_type:?}"),
+            PdnError::InvalidFen(error) => write!(f, "invalid FEN: {error}"),
+            PdnError::InvalidToken(token) => write!(f, "unexpected text in the moves: {token:?}"),
+            PdnError::IllegalMove(text) => write!(f, "illegal move: {text}"),
+            PdnError::AmbiguousMove(text) => write!(f, "ambiguous move: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for PdnError {}
+
+impl From<FenError> for PdnError {
+    fn from(error: FenError) -> Self {
+        PdnError::InvalidFen(error)
+    }
+}
+
+// `GameType` numbers of the PDN standard. Giveaway has no number of its own, it is saved as Russian draughts
+// with the `Variant` tag pair `GIVEAWAY_VARIANT`.
+fn game_type(ruleset: Ruleset) -> u8 {
+    match ruleset {
+        Ruleset::International => 20,
+        Ruleset::English => 21,
+        Ruleset::Italian => 22,
+        Ruleset::Russian | Ruleset::Giveaway => 25,
+        Ruleset::Brazilian => 26,
+        Ruleset::Turkish => 30,
+    }
+}
+
+const GIVEAWAY_VARIANT: &str = "Giveaway";
+
+fn parse_game_type(value: &str) -> Result<Ruleset, PdnError> {
+    // The number can be followed by the board description, e.g. `25,W,8,8,A0,0`.
+    Ok(match value.split(',').next().unwrap_or("").trim() {
+        "21" => Ruleset::English,
+        "22" => Ruleset::Italian,
+        "25" => Ruleset::Russian,
+        "26" => Ruleset::Brazilian,
+        _ => return Err(PdnError::UnsupportedGameType(value.to_string())),
+    })
+}
+
+// English and Italian games are written with square numbers, the others with algebraic cells.
+fn uses_numeric_notation(ruleset: Ruleset) -> bool {
+    matches!(ruleset, Ruleset::English | Ruleset::Italian)
+}
+
+// Turns the errors of a move into the errors of the file.
+fn parse_move(game: &Game, text: &str) -> Result<Move, PdnError> {
+    game.parse_move(text).map_err(|error| match error {
+        MoveParseError::InvalidSyntax(_) | MoveParseError::NotPlayableSquare(_) => PdnError::InvalidToken(text.to_string()),
+        MoveParseError::IllegalMove(_) => PdnError::IllegalMove(text.to_string()),
+        MoveParseError::AmbiguousMove(_) => PdnError::AmbiguousMove(text.to_string()),
+    })
+}
+
+// Parts of the PDN text.
+#[derive(Debug)]
+enum Token<'a> {
+    Header(String, String),
+    Comment(&'a str),
+    Word(&'a str),
+}
+
+struct Tokenizer<'a> {
+    text: &'a str,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn parse_header(&mut self) -> Result<Token<'a>, PdnError> {
+        let text = self.text;
+        let invalid = || PdnError::InvalidHeader(text.lines().next().unwrap_or("").trim().to_string());
+        let rest = text[1..].trim_start();
+        let (name, rest) = rest.split_at(rest.find(|c: char| c.is_whitespace() || c == '"').ok_or_else(invalid)?);
+        let rest = rest.trim_start().strip_prefix('"').filter(|_| !name.is_empty()).ok_or_else(invalid)?;
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        loop {
+            match chars.next().ok_or_else(invalid)? {
+                (_, '\\') => value.push(chars.next().ok_or_else(invalid)?.1),
+                (i, '"') => {
+                    self.text = rest[i + 1..].trim_start().strip_prefix(']').ok_or_else(invalid)?;
+                    return Ok(Token::Header(name.to_string(), value));
+                }
+                (_, c) => value.push(c),
+            }
+        }
+    }
+
+    // Variations in parentheses aren't kept.
+    fn skip_variation(&mut self) -> Result<(), PdnError> {
+        let mut depth = 0;
+        let text = self.text;
+        for (i, c) in text.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                self.text = &text[i + 1..];
+                return Ok(());
+            }
+        }
+        Err(PdnError::InvalidToken(text.to_string()))
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token<'a>, PdnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let text = self.text.trim_start();
+            self.text = text;
+            return Some(match text.chars().next()? {
+                '[' => self.parse_header(),
+                '{' => match text.find('}') {
+                    Some(end) => {
+                        self.text = &text[end + 1..];
+                        Ok(Token::Comment(text[1..end].trim()))
+                    }
+                    None => Err(PdnError::InvalidToken(text.to_string())),
+                },
+                '(' => match self.skip_variation() {
+                    Ok(()) => continue,
+                    Err(error) => Err(error),
+                },
+                _ => {
+                    let end = text.find(|c: char| c.is_whitespace() || "[{(".contains(c)).unwrap_or(text.len());
+                    let (word, rest) = text.split_at(end);
+                    self.text = rest;
+                    Ok(Token::Word(word))
+                }
+            });
+        }
+    }
+}
+
+// A game record: the tag pairs, the start position, the moves with comments and the result.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PdnGame {
+    // Tag pairs in the order they were read, e.g. `("Event", "Club championship")`.
+    // `GameType`, `FEN`, `Result` and the Giveaway `Variant` are kept in `start` and `result` instead.
+    pub headers: Vec<(String, String)>,
+    // The position before the first move, its ruleset is the ruleset of the game.
+    pub start: Game,
+    pub moves: Vec<Move>,
+    // Number of the first move pair, more than 1 in games from a position of another game.
+    pub first_move_number: usize,
+    // Comments with the number of moves made before each of them.
+    pub comments: Vec<(usize, String)>,
+    pub result: PdnResult,
+}
+
+impl PdnGame {
+    pub fn new(start: Game) -> PdnGame {
+        PdnGame {
+            headers: vec![],
+            start,
+            moves: vec![],
+            first_move_number: 1,
+            comments: vec![],
+            result: PdnResult::Unknown,
+        }
+    }
+
+    // The position after all the moves.
+    pub fn end_position(&self) -> Game {
+        let mut game = self.start.clone();
+        for &m in &self.moves {
+            game.make_move(m);
+            game.change_player();
+        }
+        game
+    }
+
+    // Reads all the games of a PDN file. Games without `GameType` are read as Russian draughts,
+    // or as Giveaway with `[Variant "Giveaway"]`.
+    pub fn read_all(text: &str) -> Result<Vec<PdnGame>, PdnError> {
+        let mut games = vec![];
+        let mut tokens = Tokenizer { text }.peekable();
+        while tokens.peek().is_some() {
+            // Tag pairs.
+            let mut headers = vec![];
+            let mut ruleset = Ruleset::default();
+            let mut fen = None;
+            let mut result = PdnResult::Unknown;
+            let mut giveaway = false;
+            while let Some(Ok(Token::Header(..))) = tokens.peek() {
+                let Some(Ok(Token::Header(name, value))) = tokens.next() else { unreachable!() };
+                match name.as_str() {
+                    "GameType" => ruleset = parse_game_type(&value)?,
+                    "Variant" if value == GIVEAWAY_VARIANT => giveaway = true,
+                    "FEN" => fen = Some(value),
+                    "Result" => result = PdnResult::parse(&value).unwrap_or_default(),
+                    _ => headers.push((name, value)),
+                }
+            }
+            if giveaway {
+                if ruleset != Ruleset::Russian {
+                    return Err(PdnError::UnsupportedGameType(format!("{GIVEAWAY_VARIANT} on {ruleset}")));
+                }
+                ruleset = Ruleset::Giveaway;
+            }
+            let start = match fen {
+                Some(fen) => Game::from_fen(&fen, ruleset)?,
+                None => Game::new(ruleset),
+            };
+            let mut game = PdnGame {
+                headers,
+                result,
+                ..PdnGame::new(start.clone())
+            };
+            // Move text, up to the result or the tag pairs of the next game.
+            let mut position = start;
+            while let Some(token) = tokens.next_if(|token| !matches!(token, Ok(Token::Header(..)))) {
+                match token? {
+                    Token::Comment(comment) => game.comments.push((game.moves.len(), comment.to_string())),
+                    Token::Word(word) => {
+                        if let Some(result) = PdnResult::parse(word) {
+                            game.result = result;
+                            while let Some(Ok(Token::Comment(comment))) = tokens.next_if(|token| matches!(token, Ok(Token::Comment(_)))) {
+                                game.comments.push((game.moves.len(), comment.to_string()));
+                            }
+                            break;
+                        }
+                        // Move numbers like `12.` or `12...`, possibly written together with the move.
+                        let word = match word.find('.') {
+                            Some(dot) if word[..dot].bytes().all(|c| c.is_ascii_digit()) => {
+                                if game.moves.is_empty() && dot > 0 {
+                                    game.first_move_number = word[..dot].parse().unwrap_or(1);
+                                }
+                                word[dot..].trim_start_matches('.')
+                            }
+                            _ => word,
+                        };
+                        let word = word.trim_end_matches(['!', '?']);
+                        if word.is_empty() {
+                            continue;
+                        }
+                        let m = parse_move(&position, word)?;
+                        position.make_move(m);
+                        position.change_player();
+                        game.moves.push(m);
+                    }
+                    Token::Header(..) => unreachable!(),
+                }
+            }
+            games.push(game);
+        }
+        Ok(games)
+    }
+
+    pub fn write_all(games: &[PdnGame]) -> String {
+        games.iter().map(PdnGame::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Adds the words to lines of at most 80 characters.
+struct MoveText {
+    lines: Vec<String>,
+}
+
+impl MoveText {
+    fn push(&mut self, word: &str) {
+        match self.lines.last_mut() {
+            Some(line) if line.len() + 1 + word.len() <= 80 => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => self.lines.push(word.to_string()),
+        }
+    }
+}
+
+impl Display for PdnGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ruleset = self.start.ruleset;
+        for (name, value) in &self.headers {
+            writeln!(f, "[{name} \"{}\"]", escape(value))?;
+        }
+        writeln!(f, "[Result \"{}\"]", self.result)?;
+        writeln!(f, "[GameType \"{}\"]", game_type(ruleset))?;
+        if ruleset == Ruleset::Giveaway {
+            writeln!(f, "[Variant \"{GIVEAWAY_VARIANT}\"]")?;
+        }
+        let fen = self.start.to_fen();
+        if fen != Game::new(ruleset).to_fen() {
+            writeln!(f, "[FEN \"{fen}\"]")?;
+        }
+        writeln!(f)?;
+
+        let mut text = MoveText { lines: vec![] };
+        let mut comments = self.comments.iter().peekable();
+        let mut write_comments = |text: &mut MoveText, moves_made: usize| {
+            let mut any = false;
+            while let Some((_, comment)) = comments.next_if(|(i, _)| *i == moves_made) {
+                text.push(&format!("{{{comment}}}"));
+                any = true;
+            }
+            any
+        };
+        // Moves are numbered in pairs, starting from the player who moves first in the ruleset.
+        let first_player = ruleset.first_player();
+        let mut player = self.start.current_player;
+        let mut number = self.first_move_number;
+        let mut needs_number = true;
+        write_comments(&mut text, 0);
+        for (i, m) in self.moves.iter().enumerate() {
+            // The move number stays on the same line as the move.
+            let m = if uses_numeric_notation(ruleset) {
+                format!("{m:#}")
+            } else {
+                m.to_string()
+            };
+            if player == first_player {
+                text.push(&format!("{number}. {m}"));
+            } else if needs_number {
+                text.push(&format!("{number}... {m}"));
+            } else {
+                text.push(&m);
+            }
+            if player != first_player {
+                number += 1;
+            }
+            player = !player;
+            needs_number = write_comments(&mut text, i + 1);
+        }
+        text.push(&self.result.to_string());
+        writeln!(f, "{}", text.lines.join("\n"))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::game::{Checker, DrawReason, Move, MoveError, MoveList, PositionHistory, Winner};
+use rustc_hash::FxHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+// Everything the bots and the UI need from a game position. Implemented for the 8x8 `Game`
+// and for the 10x10 `InternationalGame`.
+pub trait Position: Clone + Eq + Hash + Display + Send + Sync + 'static {
+    // Returned by `make_move`, restores the previous position in `unmake_move`.
+    type Undo: Send;
+
+    // `true` if white, `false` if black.
+    fn current_player(&self) -> bool;
+
+    fn change_player(&mut self);
+
+    // Game evaluation for the current player.
+    fn evaluate_for_me(&self) -> i32;
+
+    // Number of half-moves since the last capture or pawn move. Older positions can't be repeated.
+    fn boring_moves_counter(&self) -> u8;
+
+    // Key of the position in the transposition table, equal positions have equal keys.
+    fn table_key(&self) -> u64 {
+        let mut hasher = FxHasher::default();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // Compares pieces placement and the player to move, ignoring counters.
+    fn is_same_position(&self, other: &Self) -> bool;
+
+    // Checks the draw rules that don't need the game history.
+    fn get_draw_reason(&self) -> Option<DrawReason>;
+
+    fn get_moves_with_takes(&self) -> MoveList;
+
+    fn get_moves_without_takes(&self) -> MoveList;
+
+    fn get_moves(&self) -> MoveList {
+        let moves_with_cutting = self.get_moves_with_takes();
+        if !moves_with_cutting.is_empty() {
+            return moves_with_cutting;
+        }
+        self.get_moves_without_takes()
+    }
+
+    // The legal move going through exactly these cells, e.g. entered by the user.
+    fn find_move(&self, path: &[i8]) -> Option<Move> {
+        self.get_moves().into_iter().find(|m| m.path() == path)
+    }
+
+    fn make_move(&mut self, move_to_make: Move) -> Self::Undo;
+
+    // `make_move` for moves from the outside, e.g. from the network or a file, checked against the legal moves.
+    // Only the cells of the move are compared, the legal move with the same cells is made.
+    fn try_make_move(&mut self, move_to_make: Move) -> Result<Self::Undo, MoveError> {
+        let legal_move = self
+            .get_moves()
+            .into_iter()
+            .find(|m| m.path() == move_to_make.path() && m.captured() == move_to_make.captured())
+            .ok_or(MoveError::IllegalMove(move_to_make))?;
+        Ok(self.make_move(legal_move))
+    }
+
+    // Takes back the move, including the player change made after it.
+    fn unmake_move(&mut self, undo: Self::Undo);
+
+    // `true` in giveaway variants, where the player who can't move wins.
+    fn no_moves_wins(&self) -> bool {
+        false
+    }
+
+    // Makes one jump of a capture sequence without finishing the move: the captured checkers stay on the board.
+    // Used to show unfinished captures. The position isn't changed if the jump is impossible.
+    fn make_capture_step(&mut self, from: i8, to: i8) -> Result<(), MoveError>;
+
+    fn get_winner(&self, history: &PositionHistory<Self>) -> Option<Winner> {
+        if self.get_moves().is_empty() {
+            return Some(if self.current_player() != self.no_moves_wins() {
+                Winner::Black
+            } else {
+                Winner::White
+            });
+        }
+        history.get_draw_reason(self).map(Winner::Draw)
+    }
+
+    // Number of cells in a row of the board.
+    fn board_size(&self) -> usize;
+
+    // Converts board coordinates (from the top left corner) into the cell number.
+    fn cell_index(&self, x: usize, y: usize) -> i8;
+
+    fn is_playable_cell(&self, x: usize, y: usize) -> bool {
+        (x + y) % 2 == 1
+    }
+
+    // Board rows from top to bottom.
+    fn get_data(&self) -> Vec<Vec<Checker>>;
+
+    // Written form of a move of this type of position, e.g. for the principal variation.
+    fn move_notation(&self, m: &Move) -> String;
+}
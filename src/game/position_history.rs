@@ -0,0 +1,54 @@
+use crate::game::{DrawReason, Game, Position};
+
+// Positions that occurred before the current one. Used to detect threefold repetition.
+#[derive(Clone)]
+pub struct PositionHistory<G: Position = Game> {
+    positions: Vec<G>,
+}
+
+impl<G: Position> Default for PositionHistory<G> {
+    fn default() -> Self {
+        PositionHistory {
+            positions: Vec::new(),
+        }
+    }
+}
+
+impl<G: Position> PositionHistory<G> {
+    #[inline(always)]
+    pub fn push(&mut self, game: &G) {
+        self.positions.push(game.clone());
+    }
+
+    #[inline(always)]
+    pub fn pop(&mut self) {
+        self.positions.pop();
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    // Number of times `game` occurred, including the current occurrence.
+    pub fn repetitions(&self, game: &G) -> usize {
+        // Positions before the last capture or pawn move can't be repeated.
+        let reversible = (game.boring_moves_counter() as usize).min(self.positions.len());
+        1 + self.positions[self.positions.len() - reversible..]
+            .iter()
+            .filter(|prev| prev.is_same_position(game))
+            .count()
+    }
+
+    pub fn get_draw_reason(&self, game: &G) -> Option<DrawReason> {
+        if self.repetitions(game) >= 3 {
+            return Some(DrawReason::Repetition);
+        }
+        game.get_draw_reason()
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+
+// Rules of the game variant. Move generation and result detection in `Game` depend on it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum Ruleset {
+    #[default]
+    Russian,
+    English,
+    Brazilian,
+    // Played on the light squares, so the board looks mirrored.
+    Italian,
+    // Russian draughts where the player who can't move wins.
+    Giveaway,
+    // Orthogonal moves on all cells, played by `TurkishGame`.
+    Turkish,
+    // 10x10 board, played by `InternationalGame`.
+    International,
+}
+
+impl Ruleset {
+    pub const ALL: [Ruleset; 7] = [
+        Ruleset::Russian,
+        Ruleset::English,
+        Ruleset::Brazilian,
+        Ruleset::Italian,
+        Ruleset::Giveaway,
+        Ruleset::Turkish,
+        Ruleset::International,
+    ];
+
+    // Number of cells in a row of the board.
+    #[inline(always)]
+    pub fn board_size(self) -> usize {
+        match self {
+            Ruleset::International => 10,
+            _ => 8,
+        }
+    }
+
+    // `true` if white, `false` if black.
+    #[inline(always)]
+    pub fn first_player(self) -> bool {
+        self != Ruleset::English
+    }
+
+    // Checkers stand on the cells with odd `x + y`, counting from the top left corner.
+    #[inline(always)]
+    pub fn plays_on_dark_cells(self) -> bool {
+        self != Ruleset::Italian
+    }
+
+    // Checkers move along rows and columns instead of diagonals.
+    #[inline(always)]
+    pub fn is_orthogonal(self) -> bool {
+        self == Ruleset::Turkish
+    }
+
+    // The variants played by `Game`, on the diagonals of an 8x8 board.
+    #[inline(always)]
+    pub fn is_played_by_game(self) -> bool {
+        self.board_size() == 8 && !self.is_orthogonal()
+    }
+
+    // Kings move and capture along the whole diagonal, not only to the neighbouring cells.
+    #[inline(always)]
+    pub fn has_flying_kings(self) -> bool {
+        match self {
+            Ruleset::Russian | Ruleset::Brazilian | Ruleset::Giveaway | Ruleset::Turkish | Ruleset::International => true,
+            Ruleset::English | Ruleset::Italian => false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn pawns_capture_backwards(self) -> bool {
+        match self {
+            Ruleset::Russian | Ruleset::Brazilian | Ruleset::Giveaway | Ruleset::International => true,
+            Ruleset::English | Ruleset::Italian | Ruleset::Turkish => false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn pawns_capture_kings(self) -> bool {
+        self != Ruleset::Italian
+    }
+
+    // If `false`, a pawn promoted during a capture continues capturing as a king.
+    #[inline(always)]
+    pub fn promotion_ends_move(self) -> bool {
+        match self {
+            Ruleset::Russian | Ruleset::Brazilian | Ruleset::Giveaway | Ruleset::Turkish | Ruleset::International => false,
+            Ruleset::English | Ruleset::Italian => true,
+        }
+    }
+
+    // If `false`, a pawn reaching the last row during a capture continues as a pawn
+    // and is promoted only if the capture ends there.
+    #[inline(always)]
+    pub fn promotes_during_capture(self) -> bool {
+        match self {
+            Ruleset::Russian | Ruleset::English | Ruleset::Italian | Ruleset::Giveaway => true,
+            Ruleset::Brazilian | Ruleset::Turkish | Ruleset::International => false,
+        }
+    }
+
+    // The capture sequence taking the most checkers is mandatory.
+    #[inline(always)]
+    pub fn has_majority_capture(self) -> bool {
+        match self {
+            Ruleset::Russian | Ruleset::English | Ruleset::Giveaway => false,
+            Ruleset::Brazilian | Ruleset::Italian | Ruleset::Turkish | Ruleset::International => true,
+        }
+    }
+
+    // The player who loses all checkers or can't move wins.
+    #[inline(always)]
+    pub fn is_giveaway(self) -> bool {
+        self == Ruleset::Giveaway
+    }
+
+    // Among the longest captures: capturing with a king first, then the most kings captured,
+    // then the kings captured earliest.
+    #[inline(always)]
+    pub fn has_king_capture_priority(self) -> bool {
+        self == Ruleset::Italian
+    }
+}
+
+impl Display for Ruleset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Ruleset::Russian => "Russian draughts",
+            Ruleset::English => "English checkers",
+            Ruleset::Brazilian => "Brazilian draughts",
+            Ruleset::Italian => "Italian draughts",
+            Ruleset::Giveaway => "Giveaway",
+            Ruleset::Turkish => "Turkish draughts",
+            Ruleset::International => "International draughts",
+        })
+    }
+}
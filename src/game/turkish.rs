@@ -0,0 +1,467 @@
+use crate::constants::*;
+use crate::game::{Checker, DrawReason, Move, MoveError, MoveList, Piece, Position};
+use crate::useful_functions::*;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+// Orthogonal directions as (dx, dy): forward for white, forward for black, left and right.
+const DIRECTIONS: [(i8, i8); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+// Turkish draughts: all 64 cells are used, checkers move and capture orthogonally, men never move backwards,
+// kings fly along rows and columns and the capture taking the most pieces is mandatory.
+// Captured checkers are removed immediately, but a king can't turn back by 180 degrees during a capture.
+// Cell numbers are the same as in `Game`.
+#[derive(Clone)]
+pub struct TurkishGame {
+    // Bitmasks for game field. If a cell is empty, all corresponding bits must be 0.
+    pub not_empty: u64,
+    pub is_white: u64,
+    pub is_queen: u64,
+    // Number of half-moves made only by kings since the last capture or man move.
+    pub boring_moves_counter: u8,
+    // `true` if white, `false` if black.
+    pub current_player: bool,
+}
+
+impl Default for TurkishGame {
+    fn default() -> Self {
+        TurkishGame::new()
+    }
+}
+
+impl TurkishGame {
+    pub fn new() -> TurkishGame {
+        TurkishGame {
+            not_empty: 0x00FF_FF00_00FF_FF00,
+            is_white: 0x0000_0000_00FF_FF00,
+            is_queen: 0,
+            boring_moves_counter: 0,
+            current_player: true,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_empty_cell(&self, i: i8) -> bool {
+        get_bit(self.not_empty, i) == 0
+    }
+
+    #[inline(always)]
+    pub fn is_white_checker(&self, i: i8) -> bool {
+        get_bit(self.is_white, i) == 1
+    }
+
+    #[inline(always)]
+    pub fn is_queen_checker(&self, i: i8) -> bool {
+        get_bit(self.is_queen, i) == 1
+    }
+
+    #[inline(always)]
+    fn get_piece(&self, i: i8) -> Piece {
+        if self.is_queen_checker(i) {
+            Piece::Queen
+        } else {
+            Piece::Pawn
+        }
+    }
+
+    #[inline(always)]
+    fn my_pieces(&self) -> u64 {
+        self.not_empty & if self.current_player { self.is_white } else { !self.is_white }
+    }
+
+    // Next cell in the given direction, if it is on the board.
+    #[inline(always)]
+    fn step(i: i8, (dx, dy): (i8, i8)) -> Option<i8> {
+        let (x, y) = conv_1d_to_2d_i8(i);
+        let (x, y) = (x + dx, y + dy);
+        if (0..8).contains(&x) && (0..8).contains(&y) {
+            Some(conv_2d_to_1d(x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    // Directions a man of the current player can move and capture in.
+    #[inline(always)]
+    fn pawn_directions(&self) -> [(i8, i8); 3] {
+        let forward = if self.current_player { DIRECTIONS[0] } else { DIRECTIONS[1] };
+        [forward, DIRECTIONS[2], DIRECTIONS[3]]
+    }
+
+    // The last row for the current player.
+    #[inline(always)]
+    fn is_promotion_cell(&self, i: i8) -> bool {
+        i > 55 && self.current_player || i < 8 && !self.current_player
+    }
+
+    #[inline(always)]
+    fn try_promote(&mut self, to: i8) {
+        if self.is_promotion_cell(to) {
+            self.is_queen |= 1 << to;
+        }
+    }
+
+    // Material and advancement of the men, for white player.
+    pub fn evaluate(&self) -> i32 {
+        let mut eval = 0;
+        let mut cells = self.not_empty;
+        while cells != 0 {
+            let i = get_bit_i(last_bit(cells));
+            cells &= cells - 1;
+            let cost = if self.is_queen_checker(i) {
+                QUEEN_COST
+            } else if self.is_white_checker(i) {
+                PAWN_COST + (i / 8) as i32
+            } else {
+                PAWN_COST + (7 - i / 8) as i32
+            };
+            eval += if self.is_white_checker(i) { cost } else { -cost };
+        }
+        eval
+    }
+
+    fn move_checker(&mut self, from: i8, to: i8) {
+        let from_mask: u64 = 1 << from;
+        let to_mask: u64 = 1 << to;
+        self.not_empty ^= from_mask ^ to_mask;
+        if self.is_white & from_mask != 0 {
+            self.is_white ^= from_mask ^ to_mask;
+        }
+        if self.is_queen & from_mask != 0 {
+            self.is_queen ^= from_mask ^ to_mask;
+        }
+    }
+
+    // Makes one jump of a capture sequence. The captured checker is removed immediately.
+    pub fn make_cutting_move(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        let captured_cell = self.find_jumped_cell(from, to).ok_or(MoveError::IllegalJump(from, to))?;
+        self.make_jump(from, to, captured_cell);
+        Ok(())
+    }
+
+    // The checker taken by a jump of the current player, if the jump is possible: exactly one enemy checker
+    // between the cells on a row or a column, and no farther than the next cell for men.
+    fn find_jumped_cell(&self, from: i8, to: i8) -> Option<i8> {
+        if !(0..64).contains(&from) || !(0..64).contains(&to) || self.is_empty_cell(from) || !self.is_empty_cell(to) {
+            return None;
+        }
+        if self.is_white_checker(from) != self.current_player {
+            return None;
+        }
+        let (fx, fy) = conv_1d_to_2d_i8(from);
+        let (tx, ty) = conv_1d_to_2d_i8(to);
+        if fx != tx && fy != ty || (tx - fx).abs() + (ty - fy).abs() > 2 && !self.is_queen_checker(from) {
+            return None;
+        }
+        let dir = ((tx - fx).signum(), (ty - fy).signum());
+        let mut jumped = None;
+        let mut curr_cell = Self::step(from, dir)?;
+        while curr_cell != to {
+            if !self.is_empty_cell(curr_cell) {
+                if jumped.is_some() {
+                    return None;
+                }
+                jumped = Some(curr_cell);
+            }
+            curr_cell = Self::step(curr_cell, dir)?;
+        }
+        jumped.filter(|&i| self.is_white_checker(i) != self.current_player)
+    }
+
+    // `make_cutting_move` with the captured checker already known.
+    fn make_jump(&mut self, from: i8, to: i8, captured_cell: i8) {
+        let mask = !(1u64 << captured_cell);
+        self.not_empty &= mask;
+        self.is_white &= mask;
+        self.is_queen &= mask;
+        self.move_checker(from, to);
+    }
+
+    // Takes back `make_jump`, `captured_queen` tells if the captured checker was a king.
+    fn unmake_jump(&mut self, from: i8, to: i8, captured_cell: i8, captured_queen: bool) {
+        self.move_checker(to, from);
+        let mask = 1u64 << captured_cell;
+        self.not_empty |= mask;
+        if !self.current_player {
+            self.is_white |= mask;
+        }
+        if captured_queen {
+            self.is_queen |= mask;
+        }
+    }
+
+    pub fn make_move(&mut self, move_to_make: Move) {
+        let (a, b) = (move_to_make.from(), move_to_make.to());
+        if move_to_make.is_capture() {
+            let path = move_to_make.path();
+            for (i, &captured_cell) in move_to_make.captured().iter().enumerate() {
+                self.make_jump(path[i], path[i + 1], captured_cell);
+            }
+            self.boring_moves_counter = 0;
+        } else {
+            if self.is_queen_checker(a) {
+                self.boring_moves_counter = self.boring_moves_counter.saturating_add(1);
+            } else {
+                self.boring_moves_counter = 0;
+            }
+            self.move_checker(a, b);
+        }
+        self.try_promote(b);
+    }
+
+    // Extends the capture `current` with every jump from its last cell. Finished captures are added to `result`,
+    // returns `false` if there are no jumps.
+    // `came_from` is the direction of the previous jump, a king can't turn back.
+    // The jumps are made and taken back in place.
+    fn find_takes(&mut self, current: &mut Move, came_from: Option<(i8, i8)>, result: &mut MoveList) -> bool {
+        let from = current.to();
+        let enemy = self.not_empty & !self.my_pieces();
+        let is_queen = self.is_queen_checker(from);
+        let pawn_directions = self.pawn_directions();
+        let directions: &[(i8, i8)] = if is_queen { &DIRECTIONS } else { &pawn_directions };
+        let mut has_jumps = false;
+        for &dir in directions {
+            if came_from == Some((-dir.0, -dir.1)) {
+                continue;
+            }
+            let mut curr_cell = from;
+            // Find the first non-empty cell on the line. Men can only capture an adjacent checker.
+            let target = loop {
+                match Self::step(curr_cell, dir) {
+                    Some(i) if self.is_empty_cell(i) && is_queen => curr_cell = i,
+                    Some(i) if !self.is_empty_cell(i) => break Some(i),
+                    _ => break None,
+                }
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if get_bit(enemy, target) == 0 {
+                continue;
+            }
+            let mut curr_cell = target;
+            while let Some(to) = Self::step(curr_cell, dir) {
+                if !self.is_empty_cell(to) {
+                    break;
+                }
+                has_jumps = true;
+                self.continue_capture(current, to, dir, target, result);
+                if !is_queen {
+                    break;
+                }
+                curr_cell = to;
+            }
+        }
+        has_jumps
+    }
+
+    // Jumps from the last cell of `current` to `to` in the direction `dir` and looks for the next jumps.
+    // The jump is taken back after.
+    fn continue_capture(&mut self, current: &mut Move, to: i8, dir: (i8, i8), captured_cell: i8, result: &mut MoveList) {
+        let from = current.to();
+        let captured_queen = self.is_queen_checker(captured_cell);
+        self.make_jump(from, to, captured_cell);
+        current.push_capture(to, captured_cell);
+        // No jumps from here, so the capture ends on this cell.
+        if !self.find_takes(current, Some(dir), result) {
+            let mut res = *current;
+            res.promotion = res.piece == Piece::Pawn && self.is_promotion_cell(to);
+            result.push_majority_capture(res);
+        }
+        current.pop_capture();
+        self.unmake_jump(from, to, captured_cell, captured_queen);
+    }
+
+    // Only the sequences capturing the most checkers are allowed.
+    pub fn get_moves_with_takes(&self) -> MoveList {
+        let mut takes = MoveList::new();
+        // The capture search makes the jumps on its own copy.
+        let mut game = self.clone();
+        let mut cells = self.my_pieces();
+        while cells != 0 {
+            let i = get_bit_i(last_bit(cells));
+            cells &= cells - 1;
+            let mut current = Move::new_capture(i, self.get_piece(i));
+            game.find_takes(&mut current, None, &mut takes);
+        }
+        takes
+    }
+
+    pub fn get_moves_without_takes(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        let mut cells = self.my_pieces();
+        while cells != 0 {
+            let from = get_bit_i(last_bit(cells));
+            cells &= cells - 1;
+            if self.is_queen_checker(from) {
+                for dir in DIRECTIONS {
+                    let mut curr_cell = from;
+                    while let Some(to) = Self::step(curr_cell, dir).filter(|&i| self.is_empty_cell(i)) {
+                        moves.push(Move::new_simple(from, to, Piece::Queen, false));
+                        curr_cell = to;
+                    }
+                }
+            } else {
+                for dir in self.pawn_directions() {
+                    if let Some(to) = Self::step(from, dir).filter(|&i| self.is_empty_cell(i)) {
+                        moves.push(Move::new_simple(from, to, Piece::Pawn, self.is_promotion_cell(to)));
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn get_data(&self) -> Vec<Vec<Checker>> {
+        (0..8)
+            .map(|y| {
+                (0..8)
+                    .map(|x| {
+                        let i = conv_2d_to_1d(x, y);
+                        match (self.is_empty_cell(i), self.is_white_checker(i), self.is_queen_checker(i)) {
+                            (true, _, _) => Checker::Empty,
+                            (false, true, false) => Checker::White,
+                            (false, true, true) => Checker::WhiteQueen,
+                            (false, false, false) => Checker::Black,
+                            (false, false, true) => Checker::BlackQueen,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Hash for TurkishGame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.not_empty);
+        state.write_u64(self.is_white);
+        state.write_u64(self.is_queen);
+        state.write_u8(self.current_player as u8);
+        state.write_u8(self.boring_moves_counter);
+    }
+}
+
+impl PartialEq for TurkishGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_same_position(other) && self.boring_moves_counter == other.boring_moves_counter
+    }
+}
+
+impl Eq for TurkishGame {}
+
+impl Display for TurkishGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "  a b c d e f g h\n{}\n{}'s move",
+            self.get_data()
+                .iter()
+                .map(|row| row
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" "))
+                .enumerate()
+                .map(|(i, x)| format!("{} {}", 8 - i, x))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            if self.current_player { "White" } else { "Black" }
+        )
+    }
+}
+
+impl Debug for TurkishGame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Position for TurkishGame {
+    // The position is small and has no heap data, so the whole previous position is the undo record.
+    type Undo = TurkishGame;
+
+    #[inline(always)]
+    fn current_player(&self) -> bool {
+        self.current_player
+    }
+
+    #[inline(always)]
+    fn change_player(&mut self) {
+        self.current_player = !self.current_player;
+    }
+
+    #[inline(always)]
+    fn evaluate_for_me(&self) -> i32 {
+        self.evaluate() * (self.current_player as i32 * 2 - 1)
+    }
+
+    #[inline(always)]
+    fn boring_moves_counter(&self) -> u8 {
+        self.boring_moves_counter
+    }
+
+    #[inline(always)]
+    fn is_same_position(&self, other: &TurkishGame) -> bool {
+        (self.not_empty, self.is_white, self.is_queen, self.current_player)
+            == (other.not_empty, other.is_white, other.is_queen, other.current_player)
+    }
+
+    // Turkish draughts has no move count limits, only repetition ends the game in a draw.
+    #[inline(always)]
+    fn get_draw_reason(&self) -> Option<DrawReason> {
+        None
+    }
+
+    #[inline(always)]
+    fn get_moves_with_takes(&self) -> MoveList {
+        TurkishGame::get_moves_with_takes(self)
+    }
+
+    #[inline(always)]
+    fn get_moves_without_takes(&self) -> MoveList {
+        TurkishGame::get_moves_without_takes(self)
+    }
+
+    #[inline(always)]
+    fn make_move(&mut self, move_to_make: Move) -> TurkishGame {
+        let undo = self.clone();
+        TurkishGame::make_move(self, move_to_make);
+        undo
+    }
+
+    #[inline(always)]
+    fn unmake_move(&mut self, undo: TurkishGame) {
+        *self = undo;
+    }
+
+    #[inline(always)]
+    fn make_capture_step(&mut self, from: i8, to: i8) -> Result<(), MoveError> {
+        self.make_cutting_move(from, to)
+    }
+
+    #[inline(always)]
+    fn board_size(&self) -> usize {
+        8
+    }
+
+    #[inline(always)]
+    fn cell_index(&self, x: usize, y: usize) -> i8 {
+        conv_2d_to_1d(x, y)
+    }
+
+    #[inline(always)]
+    fn is_playable_cell(&self, _x: usize, _y: usize) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn get_data(&self) -> Vec<Vec<Checker>> {
+        TurkishGame::get_data(self)
+    }
+
+    // Algebraic cells like in `Game`. Every cell is playable, so there are no square numbers.
+    fn move_notation(&self, m: &Move) -> String {
+        m.to_string()
+    }
+}
@@ -0,0 +1,114 @@
+use crate::game::notation::{cell_name, is_playable_cell};
+use crate::game::{Game, Move, Ruleset};
+use crate::useful_functions::{conv_1d_to_2d, get_bit_i, last_bit};
+use std::fmt::{Display, Formatter};
+
+// An inconsistency of a position built by changing the fields of `Game` directly.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PositionError {
+    // `is_white` or `is_queen` bit of an empty cell.
+    WhiteBitOnEmptyCell(String),
+    QueenBitOnEmptyCell(String),
+    // A checker on a cell checkers can't stand on in this ruleset.
+    NotPlayableCell(String),
+    // A pawn on the last row, where it should have become a king.
+    PawnOnPromotionRow(String),
+    // `captured` is only set in the middle of a capture sequence.
+    UnfinishedCapture,
+    WrongEvaluation { expected: i32, actual: i32 },
+    WrongKey { expected: u64, actual: u64 },
+    // The ruleset is played by another type of position than `Game`, so nothing else is checked.
+    UnsupportedRuleset(Ruleset),
+}
+
+impl Display for PositionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PositionError::WhiteBitOnEmptyCell(cell) => write!(f, "empty cell {cell} is marked as white"),
+            PositionError::QueenBitOnEmptyCell(cell) => write!(f, "empty cell {cell} is marked as a king"),
+            PositionError::NotPlayableCell(cell) => write!(f, "checkers can't stand on {cell}"),
+            PositionError::PawnOnPromotionRow(cell) => write!(f, "pawn on the promotion row at {cell}"),
+            PositionError::UnfinishedCapture => write!(f, "unfinished capture"),
+            PositionError::WrongEvaluation { expected, actual } => {
+                write!(f, "evaluation is {actual}, but the checkers give {expected}")
+            }
+            PositionError::WrongKey { expected, actual } => {
+                write!(f, "Zobrist key is {actual:#x}, but the position gives {expected:#x}")
+            }
+            PositionError::UnsupportedRuleset(ruleset) => write!(f, "{ruleset} isn't played by this type of position"),
+        }
+    }
+}
+
+impl std::error::Error for PositionError {}
+
+// Why a move or a jump from the outside couldn't be made.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MoveError {
+    // Not one of the legal moves of the position.
+    IllegalMove(Move),
+    // A jump that doesn't take exactly one enemy checker with a checker of the current player.
+    IllegalJump(i8, i8),
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::IllegalMove(m) => write!(f, "illegal move: {:?}", m.path()),
+            MoveError::IllegalJump(from, to) => write!(f, "illegal jump from cell {from} to cell {to}"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+// Cells of the set bits.
+fn cells(mut mask: u64) -> impl Iterator<Item = i8> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let i = get_bit_i(last_bit(mask));
+        mask &= mask - 1;
+        Some(i)
+    })
+}
+
+impl Game {
+    // Checks the invariants the move generator and the incremental updates rely on.
+    // Returns every violation found.
+    pub fn validate(&self) -> Result<(), Vec<PositionError>> {
+        if !self.ruleset.is_played_by_game() {
+            return Err(vec![PositionError::UnsupportedRuleset(self.ruleset)]);
+        }
+        let mut errors = Vec::new();
+        errors.extend(cells(self.is_white & !self.not_empty).map(|i| PositionError::WhiteBitOnEmptyCell(cell_name(i))));
+        errors.extend(cells(self.is_queen & !self.not_empty).map(|i| PositionError::QueenBitOnEmptyCell(cell_name(i))));
+        for i in cells(self.not_empty) {
+            let (x, y) = conv_1d_to_2d(i);
+            if !is_playable_cell(x, y, self.ruleset) {
+                errors.push(PositionError::NotPlayableCell(cell_name(i)));
+            }
+        }
+        let white_pawns = self.not_empty & self.is_white & !self.is_queen;
+        let black_pawns = self.not_empty & !self.is_white & !self.is_queen;
+        let pawns_on_last_row = white_pawns & 0xFF << 56 | black_pawns & 0xFF;
+        errors.extend(cells(pawns_on_last_row).map(|i| PositionError::PawnOnPromotionRow(cell_name(i))));
+        if self.captured != 0 {
+            errors.push(PositionError::UnfinishedCapture);
+        }
+        let expected = self.compute_eval_white();
+        if self.eval_white != expected {
+            errors.push(PositionError::WrongEvaluation { expected, actual: self.eval_white });
+        }
+        let expected = self.compute_key();
+        if self.key() != expected {
+            errors.push(PositionError::WrongKey { expected, actual: self.key() });
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Random numbers for Zobrist hashing of `Game` positions. They are generated at compile time,
+// so the keys are the same in every build.
+
+// Indexed by `piece_index`, then by cell number.
+pub const ZOBRIST_PIECES: [[u64; 64]; 4] = generate_pieces();
+// The next number of the sequence after the pieces.
+pub const ZOBRIST_BLACK_TO_MOVE: u64 = splitmix64(GOLDEN_GAMMA.wrapping_mul(4 * 64)).1;
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[inline(always)]
+pub const fn piece_index(is_white: bool, is_queen: bool) -> usize {
+    (is_white as usize) << 1 | is_queen as usize
+}
+
+// Returns the next state and a random number.
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(GOLDEN_GAMMA);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (state, z ^ (z >> 31))
+}
+
+const fn generate_pieces() -> [[u64; 64]; 4] {
+    let mut res = [[0; 64]; 4];
+    let mut state = 0;
+    let mut piece = 0;
+    while piece < 4 {
+        let mut cell = 0;
+        while cell < 64 {
+            let (next_state, value) = splitmix64(state);
+            state = next_state;
+            res[piece][cell] = value;
+            cell += 1;
+        }
+        piece += 1;
+    }
+    res
+}
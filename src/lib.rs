@@ -0,0 +1,6 @@
+#![allow(clippy::unusual_byte_groupings)]
+
+pub mod bot;
+pub mod constants;
+pub mod game;
+pub mod useful_functions;
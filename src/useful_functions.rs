@@ -44,7 +44,7 @@ pub fn get_bit_i_or(mask: u64, val: i8) -> i8 {
     if mask == 0 {
         return val;
     }
-    return get_bit_i(mask);
+    get_bit_i(mask)
 }
 
 #[inline(always)]
@@ -78,12 +78,35 @@ pub fn conv_2d_to_1d(x: usize, y: usize) -> i8 {
     i
 }
 
+#[inline(always)]
+pub fn conv_1d_to_2d_10x10(i: i8) -> (usize, usize) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(i >= 0, "i = {}", i);
+        assert!(i < 100, "i = {}", i);
+    }
+    ((9 - i % 10) as usize, (9 - i / 10) as usize)
+}
+
+#[inline(always)]
+pub fn conv_2d_to_1d_10x10(x: usize, y: usize) -> i8 {
+    let i = 99 - x as i8 - 10 * y as i8;
+    #[cfg(debug_assertions)]
+    {
+        assert!(i >= 0, "i = {}", i);
+        assert!(i < 100, "i = {}", i);
+    }
+    i
+}
 
 pub const EXCLUDE_RIGHT_COLUMN: u64 = 0b_1111_1110___1111_1110___1111_1110___1111_1110___1111_1110___1111_1110___1111_1110___1111_1110;
 pub const EXCLUDE_LEFT_COLUMN: u64 =  0b_0111_1111___0111_1111___0111_1111___0111_1111___0111_1111___0111_1111___0111_1111___0111_1111;
 pub const EXCLUDE_2_RIGHT_COLUMNS: u64 = 0b_1111_1100___1111_1100___1111_1100___1111_1100___1111_1100___1111_1100___1111_1100___1111_1100;
 pub const EXCLUDE_2_LEFT_COLUMNS: u64 =  0b_0011_1111___0011_1111___0011_1111___0011_1111___0011_1111___0011_1111___0011_1111___0011_1111;
 
+// The main diagonal a1-h8 ("main road").
+pub const MAIN_ROAD: u64 = 0x0102_0408_1020_4080;
+
 // These arrays contain numbers whose bits correspond to diagonals on the game board (excluding the current cell).
 // It is an easy way to get diagonals containing the given cell.
 pub const DIAGONALS: [u64; 64] = [
@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use checkers_cpu::game::{Game, InternationalGame, Move, Position, TurkishGame};
+use checkers_cpu::useful_functions::{conv_2d_to_1d, conv_2d_to_1d_10x10};
+
+pub fn cell(name: &str) -> i8 {
+    let bytes = name.as_bytes();
+    conv_2d_to_1d((bytes[0] - b'a') as usize, (b'8' - bytes[1]) as usize)
+}
+
+pub fn path(cells: &[&str]) -> Vec<i8> {
+    cells.iter().map(|name| cell(name)).collect()
+}
+
+// Masks of the pieces: `(not_empty, is_white, is_queen)`. Kings are written with a `K` prefix, e.g. `Ka1`.
+fn piece_masks(white: &[&str], black: &[&str], cell: fn(&str) -> i8) -> (u128, u128, u128) {
+    let (mut not_empty, mut is_white, mut is_queen) = (0, 0, 0);
+    for (pieces, white) in [(white, true), (black, false)] {
+        for name in pieces {
+            let (king, name) = match name.strip_prefix('K') {
+                Some(name) => (true, name),
+                None => (false, *name),
+            };
+            let mask = 1 << cell(name);
+            not_empty |= mask;
+            if white {
+                is_white |= mask;
+            }
+            if king {
+                is_queen |= mask;
+            }
+        }
+    }
+    (not_empty, is_white, is_queen)
+}
+
+// Builds a position with white to move. Kings are written with a `K` prefix, e.g. `Ka1`.
+pub fn position(white: &[&str], black: &[&str]) -> Game {
+    let (not_empty, is_white, is_queen) = piece_masks(white, black, cell);
+    let mut game = Game::default();
+    game.not_empty = not_empty as u64;
+    game.is_white = is_white as u64;
+    game.is_queen = is_queen as u64;
+    game.eval_white = game.compute_eval_white();
+    game.update_key();
+    game
+}
+
+// `position` for Turkish draughts, where every cell is playable.
+pub fn turkish_position(white: &[&str], black: &[&str]) -> TurkishGame {
+    let (not_empty, is_white, is_queen) = piece_masks(white, black, cell);
+    TurkishGame {
+        not_empty: not_empty as u64,
+        is_white: is_white as u64,
+        is_queen: is_queen as u64,
+        ..Default::default()
+    }
+}
+
+// Cells of the 10x10 board, from `a1` to `j10`.
+pub fn cell_10x10(name: &str) -> i8 {
+    let x = (name.as_bytes()[0] - b'a') as usize;
+    let rank: usize = name[1..].parse().unwrap();
+    conv_2d_to_1d_10x10(x, 10 - rank)
+}
+
+pub fn path_10x10(cells: &[&str]) -> Vec<i8> {
+    cells.iter().map(|name| cell_10x10(name)).collect()
+}
+
+// `position` for international draughts on the 10x10 board.
+pub fn international_position(white: &[&str], black: &[&str]) -> InternationalGame {
+    let (not_empty, is_white, is_queen) = piece_masks(white, black, cell_10x10);
+    InternationalGame {
+        not_empty,
+        is_white,
+        is_queen,
+        ..Default::default()
+    }
+}
+
+pub fn sorted_paths<G: Position>(game: &G) -> Vec<Vec<i8>> {
+    let mut paths: Vec<Vec<i8>> = game.get_moves().iter().map(Move::as_vec).collect();
+    paths.sort();
+    paths
+}
+
+// Number of move sequences of the given length.
+pub fn perft<G: Position>(game: &G, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    game.get_moves().into_iter().map(|m| {
+        let mut game_copy = game.clone();
+        game_copy.make_move(m);
+        game_copy.change_player();
+        perft(&game_copy, depth - 1)
+    }).sum()
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use checkers_cpu::game::{DiagramError, Game, Ruleset};
+use common::position;
+
+// Every position of random games is read back from its diagram.
+#[test]
+fn parse_displayed_games() {
+    let mut rng = fastrand::Rng::with_seed(17);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..20 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..100 {
+                let mut parsed = Game::from_diagram(&game.to_string(), ruleset).unwrap();
+                // The diagram has no move counters.
+                parsed.boring_moves_counter = game.boring_moves_counter;
+                parsed.balance_moves_counter = game.balance_moves_counter;
+                assert!(parsed == game, "{ruleset}\n{game}");
+                assert_eq!(parsed.eval_white, game.eval_white, "{ruleset}\n{game}");
+                assert_eq!(parsed.key(), game.key(), "{ruleset}\n{game}");
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn coordinates_and_whitespace_are_optional() {
+    let text = "
+        . . . . . . . .
+        . . . . B . . .
+        ........
+        . . b . . . . .
+        . . . w . . . .
+        ........
+        . . . . . . . .
+        W . . . . . . .
+
+        Black's move
+    ";
+    let game: Game = text.parse().unwrap();
+    let mut expected = position(&["d4", "Ka1"], &["c5", "Ke7"]);
+    expected.current_player = false;
+    expected.eval_white = expected.compute_eval_white();
+    expected.update_key();
+    assert!(game == expected, "{game}");
+    assert_eq!(game.eval_white, expected.eval_white);
+    assert_eq!(game.key(), expected.key());
+    assert!(Game::default().to_string().parse::<Game>().unwrap() == Game::default());
+}
+
+#[test]
+fn errors() {
+    let diagram = Game::default().to_string();
+    let parse = |text: &str| text.parse::<Game>().err();
+    assert_eq!(parse(""), Some(DiagramError::InvalidFormat));
+    assert_eq!(parse(&diagram.replace("1 w . w . w . w .\n", "")), Some(DiagramError::InvalidFormat));
+    assert_eq!(parse(&diagram.replace("8 . b", "8 . x")), Some(DiagramError::InvalidRow("8.x.b.b.b".to_string())));
+    assert_eq!(parse(&diagram.replace("8 . b", "7 . b")), Some(DiagramError::InvalidRow("7.b.b.b.b".to_string())));
+    assert_eq!(parse(&diagram.replace("8 . b", "8 b b")), Some(DiagramError::NotPlayableCell("a8".to_string())));
+    assert_eq!(
+        parse(&diagram.replace("White's move", "Red's move")),
+        Some(DiagramError::InvalidSideToMove("Red'smove".to_string()))
+    );
+    // Italian is played on the light cells.
+    assert_eq!(Game::from_diagram(&diagram, Ruleset::Italian).err(), Some(DiagramError::NotPlayableCell("b8".to_string())));
+    let italian = Game::new(Ruleset::Italian);
+    assert!(Game::from_diagram(&italian.to_string(), Ruleset::Italian).unwrap() == italian);
+    for ruleset in [Ruleset::Turkish, Ruleset::International] {
+        assert_eq!(Game::from_diagram(&diagram, ruleset).err(), Some(DiagramError::UnsupportedRuleset(ruleset)));
+    }
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use checkers_cpu::game::{DrawReason, Game, Position, PositionHistory, Winner};
+use common::{cell, position};
+
+fn play(game: &mut Game, history: &mut PositionHistory, from: &str, to: &str) {
+    history.push(game);
+    game.make_move(game.find_move(&[cell(from), cell(to)]).unwrap());
+    game.change_player();
+}
+
+#[test]
+fn threefold_repetition() {
+    let mut game = position(&["Ka3", "c1"], &["Kh8", "f8"]);
+    let mut history = PositionHistory::default();
+    for _ in 0..2 {
+        assert_eq!(game.get_winner(&history), None);
+        play(&mut game, &mut history, "a3", "b4");
+        play(&mut game, &mut history, "h8", "g7");
+        play(&mut game, &mut history, "b4", "a3");
+        play(&mut game, &mut history, "g7", "h8");
+    }
+    assert_eq!(history.repetitions(&game), 3);
+    assert_eq!(game.get_winner(&history), Some(Winner::Draw(DrawReason::Repetition)));
+}
+
+#[test]
+fn pawn_move_breaks_repetition() {
+    let mut game = position(&["Ka3", "c1"], &["Kh8", "f8"]);
+    let mut history = PositionHistory::default();
+    play(&mut game, &mut history, "a3", "b4");
+    play(&mut game, &mut history, "h8", "g7");
+    play(&mut game, &mut history, "c1", "d2");
+    assert_eq!(game.boring_moves_counter, 0);
+    assert_eq!(history.repetitions(&game), 1);
+}
+
+#[test]
+fn king_moves_only() {
+    let mut game = position(&["Ka1", "c1"], &["Kh8", "f8"]);
+    game.boring_moves_counter = 29;
+    game.balance_moves_counter = 29;
+    assert_eq!(game.get_draw_reason(), None);
+    game.make_move(game.find_move(&[cell("a1"), cell("b2")]).unwrap());
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::KingMoves));
+    // The rule only applies when both players have kings.
+    let mut game = position(&["Ka1", "c1"], &["f8", "h8"]);
+    game.boring_moves_counter = 40;
+    game.balance_moves_counter = 40;
+    assert_eq!(game.get_draw_reason(), None);
+}
+
+#[test]
+fn three_kings_against_one() {
+    let mut game = position(&["Ka1", "Kc1", "Ke1"], &["Kh6"]);
+    game.balance_moves_counter = 10;
+    assert_eq!(game.get_draw_reason(), None);
+    game.balance_moves_counter = 30;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::ThreeKingsAgainstOne));
+    // The lone king holds the main road.
+    let mut game = position(&["Kb2"], &["Kd8", "Kf8", "Kh6"]);
+    game.balance_moves_counter = 9;
+    assert_eq!(game.get_draw_reason(), None);
+    game.balance_moves_counter = 10;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::ThreeKingsAgainstOne));
+}
+
+#[test]
+fn endgame_move_limits() {
+    let mut game = position(&["Ka1", "Kc1", "e1"], &["Kh6", "Kh8"]);
+    game.balance_moves_counter = 59;
+    assert_eq!(game.get_draw_reason(), None);
+    game.balance_moves_counter = 60;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::EndgameMoveLimit));
+    let mut game = position(&["Ka1", "Kc1", "e1", "g1"], &["Kh6", "Kh8", "b8"]);
+    game.balance_moves_counter = 119;
+    assert_eq!(game.get_draw_reason(), None);
+    game.balance_moves_counter = 120;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::EndgameMoveLimit));
+}
+
+#[test]
+fn promotion_resets_balance_counter() {
+    let mut game = position(&["Ka1", "c7"], &["Kh6"]);
+    game.balance_moves_counter = 20;
+    game.make_move(game.find_move(&[cell("c7"), cell("d8")]).unwrap());
+    assert!(game.is_queen_checker(cell("d8")));
+    assert_eq!(game.balance_moves_counter, 0);
+    assert_eq!(game.boring_moves_counter, 0);
+}
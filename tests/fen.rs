@@ -0,0 +1,84 @@
+mod common;
+
+use checkers_cpu::game::{FenError, Game, Ruleset};
+use common::position;
+
+#[test]
+fn start_positions() {
+    for ruleset in [Ruleset::Russian, Ruleset::Brazilian, Ruleset::Italian, Ruleset::Giveaway] {
+        let game = Game::new(ruleset);
+        let fen = "W:W21,22,23,24,25,26,27,28,29,30,31,32:B1,2,3,4,5,6,7,8,9,10,11,12";
+        assert_eq!(game.to_fen(), fen, "{ruleset}");
+        let parsed = Game::from_fen(fen, ruleset).unwrap();
+        assert!(parsed == game, "{ruleset}");
+        assert_eq!((parsed.eval_white, parsed.key()), (game.eval_white, game.key()), "{ruleset}");
+    }
+    let english = Game::from_fen("B:W21-32:B1-12", Ruleset::English).unwrap();
+    assert!(english == Game::new(Ruleset::English));
+}
+
+#[test]
+fn squares_and_kings() {
+    let game = Game::from_fen("W:W21,22,K5:B1,2,3", Ruleset::Russian).unwrap();
+    assert!(game == position(&["a3", "c3", "Ka7"], &["b8", "d8", "f8"]));
+    assert_eq!(game.to_fen(), "W:W21,22,K5:B1,2,3");
+
+    let game = Game::from_fen(" B:BKh8, f6:W c3,Ka1 .", Ruleset::Russian).unwrap();
+    assert!(!game.current_player);
+    assert_eq!(game.to_fen(), "B:W22,K29:B11,K4");
+
+    // In Italian draughts the first square is in the top left corner too, which is a light cell.
+    let game = Game::from_fen("W:W32:B1", Ruleset::Italian).unwrap();
+    assert_eq!(game.to_fen(), "W:W32:B1");
+    assert!(Game::from_fen("W:Wh1:Ba8", Ruleset::Italian).unwrap() == game);
+}
+
+// Positions loaded from FEN have the same evaluation and key as the ones reached by playing.
+#[test]
+fn round_trip_of_played_positions() {
+    let mut rng = fastrand::Rng::with_seed(3);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..20 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..150 {
+                let mut parsed = Game::from_fen(&game.to_fen(), ruleset).unwrap();
+                // FEN has no move counters.
+                parsed.boring_moves_counter = game.boring_moves_counter;
+                parsed.balance_moves_counter = game.balance_moves_counter;
+                assert!(parsed == game, "{ruleset}\n{game}");
+                assert_eq!(parsed.eval_white, game.eval_white, "{ruleset}\n{game}");
+                assert_eq!(parsed.key(), game.key(), "{ruleset}\n{game}");
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    let parse = |fen| Game::from_fen(fen, Ruleset::Russian).err();
+    assert_eq!(parse("W:W21"), Some(FenError::InvalidFormat));
+    assert_eq!(parse("W:W21:B1:H0"), Some(FenError::InvalidFormat));
+    assert_eq!(parse("X:W21:B1"), Some(FenError::InvalidSideToMove("X".to_string())));
+    assert_eq!(parse("W:W21:W1"), Some(FenError::InvalidColor("W1".to_string())));
+    assert_eq!(parse("W:R21:B1"), Some(FenError::InvalidColor("R21".to_string())));
+    assert_eq!(parse("W:W33:B1"), Some(FenError::InvalidSquare("33".to_string())));
+    assert_eq!(parse("W:W0:B1"), Some(FenError::InvalidSquare("0".to_string())));
+    assert_eq!(parse("W:W5-3:B1"), Some(FenError::InvalidSquare("5-3".to_string())));
+    assert_eq!(parse("W:Wz9:B1"), Some(FenError::InvalidSquare("z9".to_string())));
+    assert_eq!(parse("W:Wa2:B1"), Some(FenError::NotPlayableSquare("a2".to_string())));
+    assert_eq!(parse("W:W22,c3:B1"), Some(FenError::DuplicateSquare("22".to_string())));
+    assert_eq!(parse("W:W21:B1-4,3"), Some(FenError::DuplicateSquare("3".to_string())));
+    assert_eq!(parse("W:W2:B10"), Some(FenError::PawnOnPromotionRow("2".to_string())));
+    assert_eq!(parse("W:W21:B30"), Some(FenError::PawnOnPromotionRow("30".to_string())));
+    assert!(parse("W:WK2:BK30").is_none());
+    for ruleset in [Ruleset::Turkish, Ruleset::International] {
+        assert_eq!(Game::from_fen("W:W21:B1", ruleset).err(), Some(FenError::UnsupportedRuleset(ruleset)));
+    }
+    assert_eq!(FenError::DuplicateSquare("3".to_string()).to_string(), "square 3 is listed twice");
+}
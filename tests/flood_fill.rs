@@ -0,0 +1,29 @@
+use checkers_cpu::game::flood_fill::{king_jump_targets, king_slide_targets};
+use checkers_cpu::game::magic_numbers::*;
+use checkers_cpu::useful_functions::DIAGONALS;
+
+// Both backends give the same cells for every set of checkers on the diagonals of every cell.
+#[test]
+fn same_as_magic_tables() {
+    for from in (0..64).filter(|&i| MAGIC_INDEX_BITS[i as usize] != 0) {
+        for checkers in diagonal_subsets(from) {
+            let i = magic_index(from, checkers);
+            assert_eq!(king_slide_targets(from, checkers), MOVES_WITHOUT_CAPTURES[i], "{from} {checkers:b}");
+            assert_eq!(king_jump_targets(from, checkers), MOVES_WITH_CAPTURES[i], "{from} {checkers:b}");
+        }
+    }
+}
+
+// Checkers off the diagonals of the cell, including the cell itself, don't change the result.
+#[test]
+fn whole_board_masks() {
+    let mut rng = fastrand::Rng::with_seed(5);
+    for from in 0..64 {
+        for _ in 0..1000 {
+            let checkers = rng.u64(..) & rng.u64(..);
+            let on_diagonals = checkers & DIAGONALS[from as usize];
+            assert_eq!(king_slide_targets(from, checkers), slide_targets(from, on_diagonals), "{from} {checkers:b}");
+            assert_eq!(king_jump_targets(from, checkers), jump_targets(from, on_diagonals), "{from} {checkers:b}");
+        }
+    }
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot};
+use checkers_cpu::game::{DrawReason, InternationalGame, Position, PositionHistory};
+use common::{cell_10x10 as cell, international_position as position, path_10x10 as path, perft, sorted_paths};
+use std::fmt::Write;
+
+#[test]
+fn start_position_perft() {
+    let game = InternationalGame::new();
+    assert_eq!(game.not_empty.count_ones(), 40);
+    let expected = [9, 81, 658, 4265, 27117];
+    for (depth, nodes) in expected.into_iter().enumerate() {
+        assert_eq!(perft(&game, depth as u32 + 1), nodes, "depth {}", depth + 1);
+    }
+}
+
+#[test]
+fn majority_capture() {
+    let game = position(&["d4"], &["c5", "e5", "g7"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d4", "f6", "h8"])]);
+}
+
+#[test]
+fn move_notation() {
+    let game = InternationalGame::new();
+    let mut notations: Vec<String> = game.get_moves().iter().map(|m| game.move_notation(m)).collect();
+    notations.sort();
+    assert_eq!(notations, ["31-26", "31-27", "32-27", "32-28", "33-28", "33-29", "34-29", "34-30", "35-30"]);
+    let game = position(&["d4"], &["c5", "e5", "g7"]);
+    assert_eq!(game.move_notation(&game.get_moves()[0]), "32x23x14");
+    // The 8x8 cell names don't fit the upper rows.
+    let game = position(&["b8"], &["i1"]);
+    let mut notations: Vec<String> = game.get_moves().iter().map(|m| game.move_notation(m)).collect();
+    notations.sort();
+    assert_eq!(notations, ["11-6", "11-7"]);
+    assert!(write!(String::new(), "{}", game.get_moves()[0]).is_err());
+}
+
+// Converts pieces given by squares 1..50 of the standard numbering, e.g. `K45`, into cell names.
+fn squares(pieces: &[&str]) -> Vec<String> {
+    pieces
+        .iter()
+        .map(|piece| {
+            let (king, n) = match piece.strip_prefix('K') {
+                Some(n) => ("K", n),
+                None => ("", *piece),
+            };
+            let n: usize = n.parse::<usize>().unwrap() - 1;
+            let row = n / 5;
+            let x = 2 * (n % 5) + (row + 1) % 2;
+            format!("{king}{}{}", (b'a' + x as u8) as char, 10 - row)
+        })
+        .collect()
+}
+
+#[test]
+fn many_king_capture_sequences() {
+    // Far more than `MAX_MOVES` shorter sequences are found before the longest ones.
+    let white = squares(&["K45", "K35", "K2"]);
+    let black = squares(&["K41", "38", "37", "34", "28", "21", "K19", "18", "K16", "15", "14", "K7", "6", "K5"]);
+    let white: Vec<&str> = white.iter().map(String::as_str).collect();
+    let black: Vec<&str> = black.iter().map(String::as_str).collect();
+    let game = position(&white, &black);
+    let moves = game.get_moves();
+    assert!(!moves.is_empty());
+    let max_len = moves[0].captured().len();
+    assert!(max_len > 1);
+    assert!(moves.iter().all(|m| m.captured().len() == max_len));
+}
+
+#[test]
+fn men_capture_backwards() {
+    let game = position(&["d4"], &["c3"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d4", "b2"])]);
+}
+
+#[test]
+fn flying_king_capture() {
+    let game = position(&["Ka1"], &["d4"]);
+    let expected: Vec<Vec<i8>> = ["e5", "f6", "g7", "h8", "i9", "j10"]
+        .into_iter()
+        .map(|to| path(&["a1", to]))
+        .collect();
+    assert_eq!(sorted_paths(&game), expected);
+}
+
+#[test]
+fn promotion_only_at_the_end_of_capture() {
+    // The man passes through the last row and leaves it, so it stays a man.
+    let mut game = position(&["d8"], &["e9", "g9"]);
+    let moves = game.get_moves();
+    assert_eq!(sorted_paths(&game), vec![path(&["d8", "f10", "h8"])]);
+    game.make_move(moves[0]);
+    assert_eq!(game.not_empty.count_ones(), 1);
+    assert!(!game.is_queen_checker(cell("h8")));
+
+    let mut game = position(&["d8"], &["e9"]);
+    game.make_move(game.find_move(&path(&["d8", "f10"])).unwrap());
+    assert!(game.is_queen_checker(cell("f10")));
+}
+
+#[test]
+fn twenty_five_move_rule() {
+    let mut game = position(&["Ka1", "Kc1"], &["Kj10", "h10"]);
+    game.boring_moves_counter = 49;
+    assert_eq!(game.get_draw_reason(), None);
+    game.boring_moves_counter = 50;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::KingMoves));
+}
+
+#[test]
+fn sixteen_move_rule() {
+    let mut game = position(&["Ka1", "c1", "e1"], &["Kj10"]);
+    game.balance_moves_counter = 31;
+    assert_eq!(game.get_draw_reason(), None);
+    game.balance_moves_counter = 32;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::EndgameMoveLimit));
+
+    let mut game = position(&["Ka1", "Kc1"], &["Kj10"]);
+    game.balance_moves_counter = 10;
+    assert_eq!(game.get_draw_reason(), Some(DrawReason::EndgameMoveLimit));
+
+    let game = position(&["Ka1", "c1", "e1", "g1"], &["Kj10"]);
+    assert_eq!(game.get_draw_reason(), None);
+}
+
+#[test]
+fn bot_plays_international() {
+    let game = position(&["d4", "a1"], &["c5", "e5", "g7", "j10"]);
+    let mut bot: NegaScoutBot<InternationalGame> = NegaScoutBot::new(3);
+    bot.start_search(game, PositionHistory::default(), 0);
+    let best_move = loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.best_move.unwrap();
+        }
+    };
+    assert_eq!(best_move.as_vec(), path(&["d4", "f6", "h8"]));
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use checkers_cpu::game::{Game, Position, Ruleset};
+use common::{cell, path, position, sorted_paths};
+
+fn italian(white: &[&str], black: &[&str]) -> Game {
+    let mut game = position(white, black);
+    game.ruleset = Ruleset::Italian;
+    game
+}
+
+#[test]
+fn initial_position_on_light_cells() {
+    let game = Game::new(Ruleset::Italian);
+    assert!(game.current_player);
+    assert_eq!(game.not_empty.count_ones(), 24);
+    for name in ["b1", "h1", "a2", "g2", "b3", "h3", "a6", "b7", "a8", "g8"] {
+        assert!(!game.is_empty_cell(cell(name)), "{name}");
+    }
+    for name in ["a1", "c3", "b6", "h8"] {
+        assert!(game.is_empty_cell(cell(name)), "{name}");
+    }
+    assert!(game.is_playable_cell(0, 0));
+    assert!(!game.is_playable_cell(1, 0));
+    assert_eq!(game.get_moves().len(), 7);
+}
+
+#[test]
+fn kings_move_one_cell() {
+    let game = italian(&["Kd3"], &["h7"]);
+    assert_eq!(
+        sorted_paths(&game),
+        vec![path(&["d3", "e2"]), path(&["d3", "c2"]), path(&["d3", "e4"]), path(&["d3", "c4"])]
+    );
+}
+
+#[test]
+fn pawns_dont_capture_kings() {
+    let game = italian(&["d3"], &["Ke4"]);
+    assert!(game.get_moves().iter().all(|m| !m.is_capture()));
+    let game = italian(&["d3"], &["Ke4", "c4"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d3", "b5"])]);
+}
+
+#[test]
+fn most_checkers_captured() {
+    let game = italian(&["d3"], &["c4", "e4", "g6"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d3", "f5", "h7"])]);
+}
+
+#[test]
+fn capture_with_king() {
+    let game = italian(&["b3", "Kf3"], &["c4", "g4"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["f3", "h5"])]);
+}
+
+#[test]
+fn most_kings_captured() {
+    let game = italian(&["Kd3"], &["c4", "Ke4"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d3", "f5"])]);
+}
+
+#[test]
+fn earliest_king_captured() {
+    let game = italian(&["Kd3"], &["Ke4", "g6", "c4", "Kc6"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d3", "f5", "h7"])]);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use checkers_cpu::game::magic_numbers::*;
+use common::cell;
+
+fn cells(names: &[&str]) -> u64 {
+    names.iter().fold(0, |mask, name| mask | 1 << cell(name))
+}
+
+#[test]
+fn ray_walking_reference() {
+    let blockers = cells(&["c3", "f6", "b6"]);
+    assert_eq!(slide_targets(cell("d4"), blockers), cells(&["e5", "c5", "e3", "f2", "g1"]));
+    let enemies = cells(&["c3", "f6", "b6", "a7"]);
+    assert_eq!(jump_targets(cell("d4"), enemies), cells(&["b2", "a1", "g7", "h8"]));
+    assert_eq!(diagonal_subsets(cell("a1")).count(), 1 << 7);
+    assert_eq!(diagonal_subsets(cell("d4")).count(), 1 << 13);
+}
+
+// Every entry of both tables is the one the slow reference gives, the entries no set of checkers uses are 0.
+#[test]
+fn tables_match_reference() {
+    assert_eq!(MOVES_WITHOUT_CAPTURES.len(), MAGIC_TABLE_SIZE);
+    assert_eq!(MOVES_WITH_CAPTURES.len(), MAGIC_TABLE_SIZE);
+    let mut used = vec![false; MAGIC_TABLE_SIZE];
+    for from in 0..64 {
+        let bits = MAGIC_INDEX_BITS[from as usize];
+        assert_eq!(bits == 0, MAGIC_NUMBERS[from as usize] == 0, "{from}");
+        if bits == 0 {
+            continue;
+        }
+        assert!(MagicChecker::new(from).is_valid(MAGIC_NUMBERS[from as usize], bits), "{from}");
+        let cell_entries = MAGIC_OFFSETS[from as usize]..MAGIC_OFFSETS[from as usize + 1];
+        for checkers in diagonal_subsets(from) {
+            let i = magic_index(from, checkers);
+            assert!(cell_entries.contains(&i), "{from} {checkers:b}");
+            used[i] = true;
+            assert_eq!(MOVES_WITHOUT_CAPTURES[i], slide_targets(from, checkers), "{from} {checkers:b}");
+            assert_eq!(MOVES_WITH_CAPTURES[i], jump_targets(from, checkers), "{from} {checkers:b}");
+        }
+    }
+    for i in (0..MAGIC_TABLE_SIZE).filter(|&i| !used[i]) {
+        assert_eq!(MOVES_WITHOUT_CAPTURES[i], 0, "{i}");
+        assert_eq!(MOVES_WITH_CAPTURES[i], 0, "{i}");
+    }
+}
+
+#[test]
+fn invalid_magic_numbers_are_found() {
+    let d4 = cell("d4");
+    let (magic_number, bits) = (MAGIC_NUMBERS[d4 as usize], MAGIC_INDEX_BITS[d4 as usize]);
+    let mut checker = MagicChecker::new(d4);
+    assert!(checker.min_index_bits() <= bits);
+    assert!(checker.is_valid(magic_number, bits));
+    assert!(!checker.is_valid(0, bits));
+    assert!(!checker.is_valid(magic_number, bits - 1));
+    assert!(!checker.is_valid(magic_number, 0));
+    assert!(checker.is_valid(magic_number, bits));
+}
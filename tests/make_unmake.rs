@@ -0,0 +1,66 @@
+mod common;
+
+use checkers_cpu::game::{AnyGame, Game, Position, Ruleset};
+use common::perft as perft_with_clone;
+
+fn state(game: &Game) -> (u64, u64, u64, i32, u8, u8, bool, u64) {
+    (
+        game.not_empty,
+        game.is_white,
+        game.is_queen,
+        game.eval_white,
+        game.boring_moves_counter,
+        game.balance_moves_counter,
+        game.current_player,
+        game.captured,
+    )
+}
+
+fn perft_with_unmake<G: Position>(game: &mut G, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let mut nodes = 0;
+    for m in game.get_moves() {
+        let undo = game.make_move(m);
+        game.change_player();
+        nodes += perft_with_unmake(game, depth - 1);
+        game.unmake_move(undo);
+    }
+    nodes
+}
+
+// Plays random games and checks that every move is taken back exactly.
+#[test]
+fn unmake_restores_position() {
+    let mut rng = fastrand::Rng::with_seed(7);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..50 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..150 {
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                for m in &moves {
+                    let before = state(&game);
+                    let undo = game.make_move(*m);
+                    game.change_player();
+                    game.unmake_move(undo);
+                    assert_eq!(state(&game), before, "{ruleset}: {m:?}\n{game}");
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn perft_is_the_same_with_unmake() {
+    for ruleset in Ruleset::ALL {
+        let mut game = AnyGame::new(ruleset);
+        let depth = if ruleset.board_size() == 8 { 5 } else { 4 };
+        assert_eq!(perft_with_unmake(&mut game, depth), perft_with_clone(&game, depth), "{ruleset}");
+    }
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use checkers_cpu::game::{Game, Move, MoveList, Piece, Ruleset};
+use common::{cell, position};
+
+fn describe(moves: &[Move]) -> Vec<(Vec<i8>, Vec<i8>, bool, bool)> {
+    let mut res: Vec<_> = moves
+        .iter()
+        .map(|m| (m.as_vec(), m.captured().to_vec(), m.piece == Piece::Queen, m.promotion))
+        .collect();
+    res.sort();
+    res
+}
+
+#[test]
+fn move_list_operations() {
+    let mut list: MoveList = (0..10).map(|i| Move::new_simple(i, i + 7, Piece::Pawn, false)).collect();
+    assert_eq!(list.len(), 10);
+    list.retain(|m| m.from() % 2 == 0);
+    assert_eq!(list.iter().map(Move::from).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+    assert_eq!(list.into_iter().map(|m| m.to()).sum::<i8>(), 55);
+    assert!(MoveList::new().is_empty());
+}
+
+#[test]
+fn majority_captures() {
+    let capture = |jumps: i8| {
+        let mut m = Move::new_capture(0, Piece::Queen);
+        for k in 0..jumps {
+            m.push_capture(2 * k + 2, 2 * k + 1);
+        }
+        m
+    };
+    let mut list = MoveList::new();
+    for jumps in [2, 1, 2, 3, 1, 3, 2] {
+        list.push_majority_capture(capture(jumps));
+    }
+    assert_eq!(list.len(), 2);
+    assert!(list.iter().all(|m| m.captured().len() == 3));
+}
+
+// Plays random games and compares the captures found by both generators in every position.
+#[test]
+fn iterative_generator_matches_recursive() {
+    let mut rng = fastrand::Rng::with_seed(11);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..50 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..150 {
+                assert_eq!(
+                    describe(&game.get_moves_with_takes()),
+                    describe(&game.get_moves_with_takes_recursive()),
+                    "{ruleset}\n{game}"
+                );
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn kings_with_many_captures() {
+    // Every king can capture in several directions with several landing cells.
+    let game = position(&["Ka1", "Kh2", "Ka7", "Kh8"], &["c3", "f4", "c5", "f6", "d2", "e7"]);
+    let moves = game.get_moves_with_takes();
+    assert!(!moves.is_empty());
+    assert_eq!(describe(&moves), describe(&game.get_moves_with_takes_recursive()));
+    assert!(moves.iter().all(|m| m.piece == Piece::Queen && m.captured().len() == m.path().len() - 1));
+    assert!(game.get_cuts_from_cell(cell("a1")).iter().all(|m| m.from() == cell("a1")));
+}
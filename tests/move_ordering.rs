@@ -0,0 +1,62 @@
+mod common;
+
+use checkers_cpu::bot::MoveOrdering;
+use checkers_cpu::game::{Game, Move};
+use common::{path, position};
+
+fn ordered_paths(ordering: &MoveOrdering, moves: &[Move], hash_move: Option<usize>, ply: usize) -> Vec<Vec<i8>> {
+    let order = ordering.order(moves, hash_move, ply);
+    order[..moves.len()].iter().map(|&i| moves[i as usize].as_vec()).collect()
+}
+
+fn find(moves: &[Move], cells: &[&str]) -> usize {
+    moves.iter().position(|m| m.as_vec() == path(cells)).unwrap()
+}
+
+#[test]
+fn generation_order_without_information() {
+    let game = Game::default();
+    let moves = game.get_moves();
+    let ordering = MoveOrdering::new();
+    let expected: Vec<_> = moves.iter().map(Move::as_vec).collect();
+    assert_eq!(ordered_paths(&ordering, &moves, None, 0), expected);
+}
+
+#[test]
+fn hash_move_first() {
+    let game = Game::default();
+    let moves = game.get_moves();
+    let ordering = MoveOrdering::new();
+    let last = moves.len() - 1;
+    assert_eq!(ordered_paths(&ordering, &moves, Some(last), 0)[0], moves[last].as_vec());
+    // A wrong index from a key collision is ignored.
+    assert_eq!(ordered_paths(&ordering, &moves, Some(200), 0).len(), moves.len());
+}
+
+#[test]
+fn longer_captures_first() {
+    let game = position(&["c3", "g3"], &["d4", "f6", "h4"]);
+    let moves = game.get_moves();
+    let ordering = MoveOrdering::new();
+    assert_eq!(ordered_paths(&ordering, &moves, None, 0)[0], path(&["c3", "e5", "g7"]));
+}
+
+#[test]
+fn killers_and_history() {
+    let game = Game::default();
+    let moves = game.get_moves();
+    let mut ordering = MoveOrdering::new();
+    let history_move = moves[find(&moves, &["g3", "h4"])];
+    let killer = moves[find(&moves, &["e3", "f4"])];
+    ordering.update(&history_move, 3, 5);
+    ordering.update(&killer, 1, 2);
+    let paths = ordered_paths(&ordering, &moves, None, 2);
+    assert_eq!(paths[0], killer.as_vec());
+    assert_eq!(paths[1], history_move.as_vec());
+    // Killers belong to their ply, the history is shared.
+    let paths = ordered_paths(&ordering, &moves, None, 3);
+    assert_eq!(paths[0], history_move.as_vec());
+    ordering.clear();
+    let expected: Vec<_> = moves.iter().map(Move::as_vec).collect();
+    assert_eq!(ordered_paths(&ordering, &moves, None, 2), expected);
+}
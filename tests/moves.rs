@@ -0,0 +1,95 @@
+mod common;
+
+use checkers_cpu::game::{Game, Move, Piece, Position, Ruleset};
+use std::collections::HashSet;
+use common::{cell, path, position};
+
+#[test]
+fn capture_records_captured_cells_in_order() {
+    let game = position(&["c3"], &["d4", "f6"]);
+    let moves = game.get_moves();
+    assert_eq!(moves.len(), 1);
+    let m = moves[0];
+    assert!(m.is_capture());
+    assert_eq!((m.from(), m.to()), (cell("c3"), cell("g7")));
+    assert_eq!(m.path(), path(&["c3", "e5", "g7"]));
+    assert_eq!(m.captured(), path(&["d4", "f6"]));
+    assert_eq!(m.piece, Piece::Pawn);
+    assert!(!m.promotion);
+}
+
+#[test]
+fn flying_king_capture_records_distant_checker() {
+    let game = position(&["Ka1"], &["d4"]);
+    let moves = game.get_moves();
+    assert_eq!(moves.len(), 4);
+    for m in moves {
+        assert_eq!(m.piece, Piece::Queen);
+        assert_eq!(m.captured(), [cell("d4")]);
+        assert!(!m.promotion);
+    }
+}
+
+#[test]
+fn simple_moves_flag_promotion() {
+    let game = position(&["g7", "Ka1"], &["a7"]);
+    for m in game.get_moves() {
+        assert!(!m.is_capture());
+        assert!(m.captured().is_empty());
+        let is_pawn = m.from() == cell("g7");
+        assert_eq!(m.piece, if is_pawn { Piece::Pawn } else { Piece::Queen });
+        assert_eq!(m.promotion, is_pawn);
+    }
+}
+
+#[test]
+fn capture_promotion_flag_depends_on_ruleset() {
+    // In Russian draughts the pawn is promoted on f8 and ends the capture as a king.
+    let game = position(&["d6"], &["e7", "g7"]);
+    let m = game.find_move(&path(&["d6", "f8", "h6"])).unwrap();
+    assert_eq!(m.captured(), path(&["e7", "g7"]));
+    assert!(m.promotion);
+    // In Brazilian draughts the promotion is lost by leaving the last row.
+    let mut game = game;
+    game.ruleset = Ruleset::Brazilian;
+    let m = game.find_move(&path(&["d6", "f8", "h6"])).unwrap();
+    assert!(!m.promotion);
+}
+
+#[test]
+fn make_move_removes_recorded_checkers() {
+    let mut game = position(&["c3"], &["d4", "f6", "h8"]);
+    let m = game.find_move(&path(&["c3", "e5", "g7"])).unwrap();
+    game.make_move(m);
+    for captured in m.captured() {
+        assert!(game.is_empty_cell(*captured));
+    }
+    assert!(!game.is_empty_cell(cell("h8")));
+    assert_eq!(game.not_empty.count_ones(), 2);
+}
+
+#[test]
+fn king_stops_where_the_capture_continues() {
+    // After taking c3 the king must land on d4 to take e3, not on e5, f6, g7 or h8.
+    let game = position(&["Ka1"], &["c3", "e3"]);
+    let moves = game.get_moves();
+    assert_eq!(moves.len(), 2);
+    for m in &moves {
+        assert_eq!(&m.path()[..2], path(&["a1", "d4"]));
+        assert_eq!(m.captured(), path(&["c3", "e3"]));
+    }
+    assert_eq!(game.get_moves_with_takes_recursive().len(), 2);
+}
+
+#[test]
+fn capture_built_by_hand_equals_generated() {
+    // The deeper branch g1:d4:f6:d8 is generated before g1:d4:b6 and leaves its cells behind.
+    let game = Game::from_fen("W:WK32,K1:B25,15,7,22,14,8,12,23", Ruleset::Russian).unwrap();
+    let mut m = Move::new_capture(cell("g1"), Piece::Queen);
+    m.push_capture(cell("d4"), cell("e3"));
+    m.push_capture(cell("b6"), cell("c5"));
+    let moves = game.get_moves();
+    assert!(moves.contains(&m));
+    let set: HashSet<Move> = moves.iter().copied().collect();
+    assert!(set.contains(&m));
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use checkers_cpu::game::{Game, MoveNotation, MoveParseError, Position, Ruleset};
+use common::{path, position};
+
+#[test]
+fn display() {
+    let game = position(&["c3", "a1"], &["d4", "f6"]);
+    let m = game.find_move(&path(&["c3", "e5", "g7"])).unwrap();
+    assert_eq!(m.to_string(), "c3:e5:g7");
+    assert_eq!(format!("{m:#}"), "22x15x8");
+    let m = Game::default().find_move(&path(&["c3", "d4"])).unwrap();
+    assert_eq!(m.to_string(), "c3-d4");
+    assert_eq!(format!("{m:#}"), "22-18");
+}
+
+// Every legal move is found again from both of its notations.
+#[test]
+fn parse_displayed_moves() {
+    let mut rng = fastrand::Rng::with_seed(13);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..20 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..100 {
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                for m in &moves {
+                    assert_eq!(game.parse_move(&m.to_string()), Ok(*m), "{ruleset}\n{game}");
+                    assert_eq!(game.parse_move(&format!("{m:#}")), Ok(*m), "{ruleset}\n{game}");
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn short_captures() {
+    let game = position(&["c3"], &["d4", "d6"]);
+    assert_eq!(game.parse_move("c3:c7").unwrap().path(), path(&["c3", "e5", "c7"]));
+    assert_eq!(game.parse_move("22x6").unwrap().path(), path(&["c3", "e5", "c7"]));
+
+    // Both captures of the four checkers around the pawn come back to c1.
+    let game = position(&["c1"], &["b2", "b4", "d2", "d4"]);
+    let Err(MoveParseError::AmbiguousMove(moves)) = game.parse_move("c1:c1") else { panic!() };
+    let mut paths: Vec<String> = moves.iter().map(|m| m.to_string()).collect();
+    paths.sort();
+    assert_eq!(paths, ["c1:a3:c5:e3:c1", "c1:e3:c5:a3:c1"]);
+    assert!(MoveParseError::AmbiguousMove(moves).to_string().starts_with("ambiguous move, it can be c1:"));
+    assert_eq!(game.parse_move("c1:e3:c5:c1").unwrap().path(), path(&["c1", "e3", "c5", "a3", "c1"]));
+}
+
+#[test]
+fn numeric_squares_depend_on_ruleset() {
+    // Square 22 is c3 on the dark cells and d3 on the light ones.
+    let italian = Game::new(Ruleset::Italian);
+    let m = italian.parse_move("22-18").unwrap();
+    assert_eq!(m.path(), path(&["d3", "c4"]));
+    assert_eq!(format!("{m:#}"), "22-18");
+    let notation: MoveNotation = "22-18".parse().unwrap();
+    assert_eq!(notation.resolve(&Game::default()).unwrap().path(), path(&["c3", "d4"]));
+}
+
+#[test]
+fn errors() {
+    let game = Game::default();
+    assert_eq!(game.parse_move("c3"), Err(MoveParseError::InvalidSyntax("c3".to_string())));
+    assert_eq!(game.parse_move("c3-z1"), Err(MoveParseError::InvalidSyntax("c3-z1".to_string())));
+    assert_eq!(game.parse_move("33-29"), Err(MoveParseError::InvalidSyntax("33-29".to_string())));
+    assert_eq!(game.parse_move("c3-b5"), Err(MoveParseError::NotPlayableSquare("b5".to_string())));
+    assert_eq!(game.parse_move(" c3-e5 "), Err(MoveParseError::IllegalMove("c3-e5".to_string())));
+    assert_eq!(game.parse_move("f6-g5"), Err(MoveParseError::IllegalMove("f6-g5".to_string())));
+    assert_eq!(MoveParseError::IllegalMove("f6-g5".to_string()).to_string(), "illegal move: f6-g5");
+}
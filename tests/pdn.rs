@@ -0,0 +1,131 @@
+mod common;
+
+use checkers_cpu::game::{Game, PdnError, PdnGame, PdnResult, Position, Ruleset, Winner};
+use common::{cell, path};
+use std::fs;
+
+fn read_file(name: &str) -> Vec<PdnGame> {
+    let text = fs::read_to_string(format!("{}/tests/pdn_files/{name}", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    PdnGame::read_all(&text).unwrap()
+}
+
+fn header<'a>(game: &'a PdnGame, name: &str) -> Option<&'a str> {
+    game.headers.iter().find(|(header, _)| header == name).map(|(_, value)| value.as_str())
+}
+
+// Every file is read, written and read again without changes.
+#[test]
+fn corpus_round_trip() {
+    let mut files = 0;
+    for entry in fs::read_dir(format!("{}/tests/pdn_files", env!("CARGO_MANIFEST_DIR"))).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        let games = read_file(&name);
+        assert!(!games.is_empty(), "{name}");
+        let written = PdnGame::write_all(&games);
+        let read_again = PdnGame::read_all(&written).unwrap();
+        assert_eq!(read_again, games, "{name}\n{written}");
+        assert_eq!(PdnGame::write_all(&read_again), written, "{name}");
+        files += 1;
+    }
+    assert!(files >= 3);
+}
+
+#[test]
+fn russian_game() {
+    let games = read_file("russian.pdn");
+    let [game] = &games[..] else { panic!() };
+    assert_eq!(game.start.ruleset, Ruleset::Russian);
+    assert_eq!(header(game, "White"), Some("Ivanov"));
+    assert_eq!(header(game, "Date"), Some("2023.11.04"));
+    assert_eq!(game.result, PdnResult::WhiteWon);
+    assert_eq!(game.moves.len(), 65);
+    // The variation is skipped, the comments are kept with their places.
+    assert_eq!(game.comments.len(), 2);
+    assert_eq!(game.comments[0], (0, "A sharp game with a long king capture at the end.".to_string()));
+    assert_eq!(game.comments[1].0, 36);
+    // `e5:h4` is the only capture from e5 to h4.
+    assert_eq!(game.moves[35].path(), path(&["e5", "c3", "e1", "h4"]));
+    assert!(game.moves[35].promotion);
+    let end = game.end_position();
+    assert_eq!(end.get_winner(&Default::default()), Some(Winner::White));
+    assert_eq!(end.not_empty, 1 << cell("c7"));
+}
+
+#[test]
+fn english_game() {
+    let games = read_file("english.pdn");
+    let [game] = &games[..] else { panic!() };
+    assert_eq!(game.start.ruleset, Ruleset::English);
+    assert!(!game.start.current_player);
+    assert_eq!(header(game, "White"), Some("Smith \"The Wall\""));
+    assert_eq!(game.result, PdnResult::Draw);
+    assert_eq!(game.moves.len(), 40);
+    // `7x30` is written with only the first and the last squares.
+    assert_eq!(game.moves[14].captured().len(), 3);
+    assert!(PdnGame::write_all(&games).contains("[White \"Smith \\\"The Wall\\\"\"]"));
+}
+
+#[test]
+fn several_games_in_a_file() {
+    let games = read_file("several_games.pdn");
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].start.ruleset, Ruleset::Italian);
+    assert_eq!(games[0].result, PdnResult::Unknown);
+    assert!(games[0].start == Game::new(Ruleset::Italian));
+
+    let study = &games[1];
+    assert_eq!(study.start.ruleset, Ruleset::Brazilian);
+    assert!(!study.start.current_player);
+    assert_eq!(study.first_move_number, 5);
+    assert_eq!(study.comments, [(3, "Forced.".to_string())]);
+    assert_eq!(study.result, PdnResult::BlackWon);
+    assert!(PdnGame::write_all(&games).contains("\n5... g5-f4 6. g3:e5"));
+}
+
+#[test]
+fn written_games_can_be_read() {
+    let mut rng = fastrand::Rng::with_seed(9);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        let mut game = Game::new(ruleset);
+        let mut record = PdnGame::new(game.clone());
+        record.headers.push(("Event".to_string(), "Random game".to_string()));
+        loop {
+            let moves = game.get_moves();
+            if moves.is_empty() || game.is_draw() {
+                break;
+            }
+            let m = moves[rng.usize(..moves.len())];
+            game.make_move(m);
+            game.change_player();
+            record.moves.push(m);
+        }
+        let read = PdnGame::read_all(&record.to_string()).unwrap();
+        assert_eq!(read, [record], "{ruleset}");
+        assert!(read[0].end_position() == game, "{ruleset}");
+    }
+}
+
+#[test]
+fn errors() {
+    let read = |text: &str| PdnGame::read_all(text).err();
+    assert_eq!(read("[Event \"x]"), Some(PdnError::InvalidHeader("[Event \"x]".to_string())));
+    assert_eq!(read("[Event]"), Some(PdnError::InvalidHeader("[Event]".to_string())));
+    assert_eq!(read("[GameType \"20\"]"), Some(PdnError::UnsupportedGameType("20".to_string())));
+    assert_eq!(
+        read("[GameType \"21\"]\n[Variant \"Giveaway\"]"),
+        Some(PdnError::UnsupportedGameType("Giveaway on English checkers".to_string()))
+    );
+    assert!(matches!(read("[FEN \"W:W21\"]"), Some(PdnError::InvalidFen(_))));
+    assert_eq!(read("1. c3-d4 {unclosed"), Some(PdnError::InvalidToken("{unclosed".to_string())));
+    assert_eq!(read("1. c3-d4 (1... f6-g5"), Some(PdnError::InvalidToken("(1... f6-g5".to_string())));
+    assert_eq!(read("1. c3-d4 hello"), Some(PdnError::InvalidToken("hello".to_string())));
+    assert_eq!(read("1. c3-b5"), Some(PdnError::InvalidToken("c3-b5".to_string())));
+    assert_eq!(read("1. c3-d4 d4-e5"), Some(PdnError::IllegalMove("d4-e5".to_string())));
+    assert_eq!(read("1. c3-e5"), Some(PdnError::IllegalMove("c3-e5".to_string())));
+
+    // Both captures of the four checkers around the pawn come back to c1.
+    let text = "[FEN \"W:Wc1:Bb2,b4,d2,d4\"]\n1. c1:c1";
+    assert_eq!(read(text), Some(PdnError::AmbiguousMove("c1:c1".to_string())));
+    let games = PdnGame::read_all(&text.replace("c1:c1", "c1:a3:c5:c1")).unwrap();
+    assert_eq!(games[0].moves[0].path(), path(&["c1", "a3", "c5", "e3", "c1"]));
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use checkers_cpu::game::{Game, Ruleset};
+use common::position;
+
+// Counts from the start position. Any mistake in the magic tables or the bitboard masks changes them.
+const ENGLISH_START: [u64; 10] = [7, 49, 302, 1469, 7361, 36768, 179740, 845931, 3963680, 18391564];
+const RUSSIAN_START: [u64; 10] = [7, 49, 302, 1469, 7482, 37986, 190146, 929905, 4570667, 22450647];
+
+// Russian draughts positions with white to move: flying kings with several landing cells,
+// promotion during a capture and the Turkish strike rule. There are no published counts for them,
+// these are snapshots of the engine checked against `reference` below, which shares no code with it.
+const RUSSIAN_POSITIONS: [(&[&str], &[&str], [u64; 6]); 5] = [
+    (
+        &["Ka1", "Kh2", "c1", "e1"],
+        &["c3", "e5", "b6", "f6", "d6", "Kh8", "g7"],
+        [1, 5, 41, 200, 1695, 8049],
+    ),
+    (
+        &["Kc1", "b4", "f2", "h4"],
+        &["d2", "c5", "e7", "Kg7", "e3", "b8"],
+        [2, 15, 42, 232, 1672, 12090],
+    ),
+    (
+        &["g5", "e3", "c3", "a3", "Kh2"],
+        &["f6", "d6", "b6", "d4", "Kb8", "h6", "f8"],
+        [6, 15, 67, 299, 1474, 9848],
+    ),
+    (
+        &["d6", "f6", "Ka1", "h2"],
+        &["e7", "c7", "g7", "Kd2", "f4", "b4"],
+        [5, 20, 177, 857, 7517, 39321],
+    ),
+    (
+        &["Kd4", "Ke1"],
+        &["c5", "e5", "c3", "f2", "b6", "g7", "Ka7"],
+        [11, 54, 280, 1612, 10559, 68779],
+    ),
+];
+
+// A slow Russian draughts move generator on a plain 8x8 array, used only to check the counts above.
+mod reference {
+    // `(white, king)` of the piece on each `[file][rank]`, `a1` is `[0][0]`.
+    pub type Board = [[Option<(bool, bool)>; 8]; 8];
+
+    const DIRECTIONS: [(i32, i32); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+    pub fn board(white: &[&str], black: &[&str]) -> Board {
+        let mut board = [[None; 8]; 8];
+        for (pieces, is_white) in [(white, true), (black, false)] {
+            for name in pieces {
+                let (king, name) = match name.strip_prefix('K') {
+                    Some(name) => (true, name),
+                    None => (false, *name),
+                };
+                let bytes = name.as_bytes();
+                board[(bytes[0] - b'a') as usize][(bytes[1] - b'1') as usize] = Some((is_white, king));
+            }
+        }
+        board
+    }
+
+    pub fn start() -> Board {
+        let mut board = [[None; 8]; 8];
+        for (file, column) in board.iter_mut().enumerate() {
+            for (rank, piece) in column.iter_mut().enumerate() {
+                if (file + rank) % 2 == 0 && rank != 3 && rank != 4 {
+                    *piece = Some((rank < 3, false));
+                }
+            }
+        }
+        board
+    }
+
+    fn get(board: &Board, x: i32, y: i32) -> Option<Option<(bool, bool)>> {
+        if (0..8).contains(&x) && (0..8).contains(&y) {
+            Some(board[x as usize][y as usize])
+        } else {
+            None
+        }
+    }
+
+    fn is_last_rank(white: bool, y: i32) -> bool {
+        y == if white { 7 } else { 0 }
+    }
+
+    // Continues a capture of the piece standing on `(x, y)` and tells if there was anything to capture.
+    // Captured pieces stay on the board until the capture ends, so they can be neither jumped again nor passed.
+    fn captures(board: &Board, x: i32, y: i32, captured: &mut Vec<(i32, i32)>, result: &mut Vec<Board>) -> bool {
+        let (white, king) = board[x as usize][y as usize].unwrap();
+        let mut found = false;
+        for (dx, dy) in DIRECTIONS {
+            let (mut tx, mut ty) = (x + dx, y + dy);
+            while king && get(board, tx, ty) == Some(None) {
+                tx += dx;
+                ty += dy;
+            }
+            match get(board, tx, ty) {
+                Some(Some((piece_white, _))) if piece_white != white && !captured.contains(&(tx, ty)) => {}
+                _ => continue,
+            }
+            // Endings from each landing cell and whether the capture went on from it.
+            let mut landings = vec![];
+            let (mut lx, mut ly) = (tx + dx, ty + dy);
+            while get(board, lx, ly) == Some(None) {
+                let mut next = *board;
+                next[x as usize][y as usize] = None;
+                next[lx as usize][ly as usize] = Some((white, king || is_last_rank(white, ly)));
+                captured.push((tx, ty));
+                let mut endings = vec![];
+                let continued = captures(&next, lx, ly, captured, &mut endings);
+                captured.pop();
+                landings.push((endings, continued));
+                if !king {
+                    break;
+                }
+                lx += dx;
+                ly += dy;
+            }
+            // A king has to stop where it can capture again, if there is such a cell.
+            let must_continue = landings.iter().any(|&(_, continued)| continued);
+            for (endings, continued) in landings {
+                found = true;
+                if continued || !must_continue {
+                    result.extend(endings);
+                }
+            }
+        }
+        if !found && !captured.is_empty() {
+            let mut end = *board;
+            for &(cx, cy) in captured.iter() {
+                end[cx as usize][cy as usize] = None;
+            }
+            result.push(end);
+        }
+        found
+    }
+
+    // Positions after each move of `white`. Captures are mandatory but any of them may be chosen.
+    pub fn moves(board: &Board, white: bool) -> Vec<Board> {
+        let pieces: Vec<(i32, i32)> = (0..64)
+            .map(|i| (i / 8, i % 8))
+            .filter(|&(x, y)| matches!(board[x as usize][y as usize], Some((w, _)) if w == white))
+            .collect();
+        let mut result = vec![];
+        for &(x, y) in &pieces {
+            captures(board, x, y, &mut vec![], &mut result);
+        }
+        if !result.is_empty() {
+            return result;
+        }
+        for &(x, y) in &pieces {
+            let king = board[x as usize][y as usize].unwrap().1;
+            for (dx, dy) in DIRECTIONS {
+                if !king && (dy == 1) != white {
+                    continue;
+                }
+                let (mut tx, mut ty) = (x + dx, y + dy);
+                while get(board, tx, ty) == Some(None) {
+                    let mut next = *board;
+                    next[x as usize][y as usize] = None;
+                    next[tx as usize][ty as usize] = Some((white, king || is_last_rank(white, ty)));
+                    result.push(next);
+                    if !king {
+                        break;
+                    }
+                    tx += dx;
+                    ty += dy;
+                }
+            }
+        }
+        result
+    }
+
+    pub fn perft(board: &Board, white: bool, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        moves(board, white).iter().map(|next| perft(next, !white, depth - 1)).sum()
+    }
+}
+
+fn check_counts(game: &mut Game, counts: &[u64]) {
+    for (depth, &count) in (1..).zip(counts) {
+        assert_eq!(game.perft(depth), count, "depth {depth}\n{game}");
+    }
+}
+
+#[test]
+fn english_start_position() {
+    check_counts(&mut Game::new(Ruleset::English), &ENGLISH_START[..8]);
+}
+
+#[test]
+fn russian_start_position() {
+    check_counts(&mut Game::new(Ruleset::Russian), &RUSSIAN_START[..8]);
+}
+
+#[test]
+fn russian_king_captures() {
+    for (white, black, counts) in RUSSIAN_POSITIONS {
+        check_counts(&mut position(white, black), &counts);
+    }
+}
+
+#[test]
+fn reference_generator_agrees() {
+    let start = reference::start();
+    for (depth, &count) in (1..).zip(&RUSSIAN_START[..6]) {
+        assert_eq!(reference::perft(&start, true, depth), count, "depth {depth}");
+    }
+    for (white, black, counts) in RUSSIAN_POSITIONS {
+        let board = reference::board(white, black);
+        for (depth, &count) in (1..).zip(&counts) {
+            assert_eq!(reference::perft(&board, true, depth), count, "depth {depth}: {white:?} {black:?}");
+        }
+    }
+}
+
+#[test]
+fn divide_sums_to_perft() {
+    let mut game = Game::default();
+    let divide = game.perft_divide(5);
+    assert_eq!(divide.len(), 7);
+    assert_eq!(divide.iter().map(|(_, count)| count).sum::<u64>(), RUSSIAN_START[4]);
+    for (m, count) in divide {
+        let undo = game.make_move(m);
+        game.change_player();
+        assert_eq!(game.perft(4), count);
+        game.unmake_move(undo);
+    }
+    assert!(game.perft_divide(0).is_empty());
+}
+
+// The full counts take a few seconds in release mode: `cargo test --release --test perft -- --ignored`.
+#[test]
+#[ignore]
+fn deep_start_positions() {
+    check_counts(&mut Game::new(Ruleset::English), &ENGLISH_START);
+    check_counts(&mut Game::new(Ruleset::Russian), &RUSSIAN_START);
+}
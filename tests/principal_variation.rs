@@ -0,0 +1,64 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot, SearchLimits, SearchResult};
+use checkers_cpu::game::{Game, PositionHistory};
+use common::{path, position};
+
+fn search(game: Game, limits: SearchLimits) -> SearchResult {
+    let mut bot: NegaScoutBot = NegaScoutBot::with_limits(limits);
+    bot.start_search(game, PositionHistory::default(), 0);
+    loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.clone();
+        }
+    }
+}
+
+// Every move of the line is legal in the position it is played in.
+fn assert_playable(mut game: Game, result: &SearchResult) {
+    for &m in &result.pv {
+        assert!(game.get_moves().contains(&m), "{m} is illegal in\n{game}");
+        game.make_move(m);
+        game.change_player();
+    }
+}
+
+#[test]
+fn pv_starts_with_best_move() {
+    let game = Game::default();
+    let result = search(game.clone(), SearchLimits::depth(6));
+    assert_eq!(result.pv.first(), result.best_move.as_ref());
+    assert!(result.pv.len() >= 2);
+    assert_playable(game, &result);
+}
+
+#[test]
+fn pv_ends_with_the_game() {
+    // The capture takes the last black checkers.
+    let game = position(&["c3", "a1"], &["d4", "f6"]);
+    let result = search(game.clone(), SearchLimits::depth(4));
+    let pv: Vec<_> = result.pv.iter().map(|m| m.as_vec()).collect();
+    assert_eq!(pv, [path(&["c3", "e5", "g7"])]);
+    assert_playable(game, &result);
+}
+
+#[test]
+fn statistics() {
+    let game = Game::default();
+    let result = search(game, SearchLimits::depth(7));
+    assert_eq!(result.depth, 7);
+    assert!(result.nodes > 1000);
+    assert!(result.time >= 0.0);
+    assert!(result.nodes_per_second() >= 0.0);
+    assert!(result.table_hit_rate > 0.0 && result.table_hit_rate <= 1.0);
+    assert!(result.to_string().starts_with(&format!("depth 7, {} nodes in ", result.nodes)));
+}
+
+#[test]
+fn no_moves() {
+    let game = position(&[], &["h8"]);
+    let result = search(game, SearchLimits::depth(3));
+    assert!(result.pv.is_empty());
+    assert_eq!(result.nodes, 0);
+    assert_eq!(result.table_hit_rate, 0.0);
+}
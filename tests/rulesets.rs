@@ -0,0 +1,121 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot};
+use checkers_cpu::game::{Game, Position, PositionHistory, Ruleset, Winner};
+use common::{cell, path, perft, position, sorted_paths};
+use std::collections::HashSet;
+
+fn with_ruleset(mut game: Game, ruleset: Ruleset) -> Game {
+    game.ruleset = ruleset;
+    game
+}
+
+#[test]
+fn first_player() {
+    assert!(Game::new(Ruleset::Russian).current_player);
+    assert!(!Game::new(Ruleset::English).current_player);
+}
+
+#[test]
+fn english_start_position_perft() {
+    let game = Game::new(Ruleset::English);
+    let expected = [7, 49, 302, 1469, 7361, 36768];
+    for (depth, nodes) in expected.into_iter().enumerate() {
+        assert_eq!(perft(&game, depth as u32 + 1), nodes, "depth {}", depth + 1);
+    }
+}
+
+#[test]
+fn pawns_capture_backwards_only_in_russian() {
+    let game = position(&["c3"], &["b4", "d2"]);
+    assert_eq!(sorted_paths(&game).len(), 2);
+    let game = with_ruleset(game, Ruleset::English);
+    assert_eq!(sorted_paths(&game), vec![path(&["c3", "a5"])]);
+}
+
+#[test]
+fn short_kings_in_english() {
+    let game = position(&["Kd4"], &["h8"]);
+    assert_eq!(sorted_paths(&game).len(), 12);
+    let game = with_ruleset(game, Ruleset::English);
+    assert_eq!(sorted_paths(&game).len(), 4);
+
+    let game = position(&["Kd4"], &["f6", "a1"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d4", "g7"]), path(&["d4", "h8"])]);
+    let game = with_ruleset(game, Ruleset::English);
+    assert_eq!(sorted_paths(&game).len(), 4);
+    assert!(game.get_moves().iter().all(|m| !m.is_capture()));
+}
+
+#[test]
+fn promotion_ends_move_only_in_english() {
+    let game = position(&["d6"], &["e7", "g7"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d6", "f8", "h6"])]);
+    let game = with_ruleset(game, Ruleset::English);
+    assert_eq!(sorted_paths(&game), vec![path(&["d6", "f8"])]);
+    let mut game = game;
+    game.make_move(game.find_move(&path(&["d6", "f8"])).unwrap());
+    assert!(game.is_queen_checker(cell("f8")));
+    assert!(!game.is_empty_cell(cell("g7")));
+}
+
+#[test]
+fn majority_capture_in_brazilian() {
+    let game = position(&["e3"], &["d4", "f4", "f6"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["e3", "g5", "e7"]), path(&["e3", "c5"])]);
+    let game = with_ruleset(game, Ruleset::Brazilian);
+    assert_eq!(sorted_paths(&game), vec![path(&["e3", "g5", "e7"])]);
+}
+
+#[test]
+fn deferred_promotion_in_brazilian() {
+    // In Russian draughts the pawn becomes a king on d8 and captures g5 along the diagonal.
+    let game = position(&["b6"], &["c7", "g5"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["b6", "d8", "h4"])]);
+    let mut game = with_ruleset(game, Ruleset::Brazilian);
+    assert_eq!(sorted_paths(&game), vec![path(&["b6", "d8"])]);
+    game.make_move(game.find_move(&path(&["b6", "d8"])).unwrap());
+    assert!(game.is_queen_checker(cell("d8")));
+
+    // The pawn passes through the last row and stays a pawn.
+    let mut game = position(&["d6"], &["e7", "g7"]);
+    game.make_move(game.find_move(&path(&["d6", "f8", "h6"])).unwrap());
+    assert!(game.is_queen_checker(cell("h6")));
+    let mut game = with_ruleset(position(&["d6"], &["e7", "g7"]), Ruleset::Brazilian);
+    game.make_move(game.find_move(&path(&["d6", "f8", "h6"])).unwrap());
+    assert!(!game.is_queen_checker(cell("h6")));
+}
+
+#[test]
+fn giveaway_winner() {
+    let game = position(&[], &["h8"]);
+    let history = PositionHistory::default();
+    assert_eq!(game.get_winner(&history), Some(Winner::Black));
+    let game = with_ruleset(game, Ruleset::Giveaway);
+    assert_eq!(game.get_winner(&history), Some(Winner::White));
+}
+
+#[test]
+fn giveaway_bot_sacrifices() {
+    let game = with_ruleset(position(&["c3"], &["e5"]), Ruleset::Giveaway);
+    let mut bot: NegaScoutBot = NegaScoutBot::new(4);
+    bot.start_search(game, PositionHistory::default(), 0);
+    let best_move = loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.best_move.unwrap();
+        }
+    };
+    assert_eq!(best_move.as_vec(), path(&["c3", "d4"]));
+}
+
+#[test]
+fn games_with_other_rules_or_counters_differ() {
+    let game = position(&["c3"], &["e5"]);
+    assert!(with_ruleset(game.clone(), Ruleset::Giveaway) != game);
+    let mut other = game.clone();
+    other.balance_moves_counter += 1;
+    assert!(other != game);
+    let games: HashSet<Game> = [game.clone(), with_ruleset(game.clone(), Ruleset::Giveaway), other].into();
+    assert_eq!(games.len(), 3);
+    assert!(games.contains(&game));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot, SearchLimits, SearchResult};
+use checkers_cpu::game::{Game, PositionHistory};
+use common::{path, position};
+use egui_macroquad::macroquad::miniquad::date;
+
+fn search(game: Game, limits: SearchLimits) -> SearchResult {
+    let mut bot: NegaScoutBot = NegaScoutBot::with_limits(limits);
+    bot.start_search(game, PositionHistory::default(), 0);
+    loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.clone();
+        }
+    }
+}
+
+fn is_legal(game: &Game, result: &SearchResult) -> bool {
+    let best_move = result.best_move.unwrap();
+    game.get_moves().into_iter().any(|m| m == best_move)
+}
+
+#[test]
+fn depth_limit() {
+    let game = position(&["c3"], &["e5"]);
+    let result = search(game, SearchLimits::depth(4));
+    assert_eq!(result.depth, 4);
+    assert_eq!(result.best_move.unwrap().as_vec(), path(&["c3", "b4"]));
+}
+
+#[test]
+fn depth_limit_matches_new() {
+    let game = Game::default();
+    let mut bot: NegaScoutBot = NegaScoutBot::new(5);
+    assert_eq!(bot.limits, SearchLimits::depth(5));
+    bot.start_search(game.clone(), PositionHistory::default(), 0);
+    let result = loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.clone();
+        }
+    };
+    assert_eq!(result.depth, 5);
+    assert!(is_legal(&game, &result));
+}
+
+#[test]
+fn first_iteration_is_always_finished() {
+    let game = Game::default();
+    let result = search(game.clone(), SearchLimits::nodes(1));
+    assert_eq!(result.depth, 1);
+    assert!(is_legal(&game, &result));
+}
+
+#[test]
+fn node_limit() {
+    let game = Game::default();
+    let small = search(game.clone(), SearchLimits::nodes(1_000));
+    let large = search(game.clone(), SearchLimits::nodes(100_000));
+    assert!(small.depth < large.depth);
+    assert!(is_legal(&game, &large));
+}
+
+#[test]
+fn time_limit() {
+    let game = Game::default();
+    let start = date::now();
+    let result = search(game.clone(), SearchLimits::time(0.2));
+    assert!(date::now() - start < 2.0);
+    assert!(result.depth >= 1);
+    assert!(is_legal(&game, &result));
+}
+
+#[test]
+fn no_moves() {
+    let game = position(&[], &["h8"]);
+    let result = search(game, SearchLimits::time(0.1));
+    assert_eq!(result.best_move, None);
+    assert_eq!(result.depth, 1);
+}
@@ -0,0 +1,83 @@
+use checkers_cpu::bot::{TranspositionTable, ValType};
+use checkers_cpu::game::{Game, Position};
+
+// Keys with the same low bits share a bucket.
+const KEY_A: u64 = 0x1111_1111_0000_0005;
+const KEY_B: u64 = 0x2222_2222_0000_0005;
+const KEY_C: u64 = 0x3333_3333_0000_0005;
+
+#[test]
+fn size() {
+    for size_mb in [1, 3, 32] {
+        let table = TranspositionTable::new(size_mb);
+        assert!(table.len().is_power_of_two());
+        assert!(table.size_in_bytes() <= size_mb << 20);
+        assert!(table.size_in_bytes() * 2 > size_mb << 20);
+    }
+    assert_eq!(TranspositionTable::new(0).len(), 2);
+}
+
+#[test]
+fn store_and_get() {
+    let mut table = TranspositionTable::new(1);
+    assert!(table.get(KEY_A).is_none());
+    table.store(KEY_A, -25, 6, ValType::Beta, Some(3));
+    let entry = table.get(KEY_A).unwrap();
+    assert_eq!(entry.eval(), -25);
+    assert_eq!(entry.depth(), 6);
+    assert_eq!(entry.val_type(), ValType::Beta);
+    assert_eq!(entry.best_move(), Some(3));
+    // Another position in the same bucket.
+    assert!(table.get(KEY_B).is_none());
+    table.clear();
+    assert!(table.get(KEY_A).is_none());
+}
+
+#[test]
+fn keeps_best_move_of_the_same_position() {
+    let mut table = TranspositionTable::new(1);
+    table.store(KEY_A, 10, 5, ValType::Exact, Some(2));
+    table.store(KEY_A, 7, 6, ValType::Alpha, None);
+    let entry = table.get(KEY_A).unwrap();
+    assert_eq!((entry.eval(), entry.depth()), (7, 6));
+    assert_eq!(entry.best_move(), Some(2));
+}
+
+#[test]
+fn depth_preferred_and_always_replace() {
+    let mut table = TranspositionTable::new(1);
+    table.store(KEY_A, 1, 8, ValType::Exact, None);
+    // Shallower entries go to the second slot and replace each other.
+    table.store(KEY_B, 2, 5, ValType::Exact, None);
+    assert_eq!(table.get(KEY_B).unwrap().eval(), 2);
+    table.store(KEY_C, 3, 4, ValType::Exact, None);
+    assert_eq!(table.get(KEY_A).unwrap().eval(), 1);
+    assert!(table.get(KEY_B).is_none());
+    assert_eq!(table.get(KEY_C).unwrap().eval(), 3);
+    // A deeper entry takes the first slot.
+    table.store(KEY_B, 4, 9, ValType::Exact, None);
+    assert!(table.get(KEY_A).is_none());
+    assert_eq!(table.get(KEY_B).unwrap().eval(), 4);
+    assert_eq!(table.get(KEY_C).unwrap().eval(), 3);
+}
+
+#[test]
+fn old_entries_are_replaced() {
+    let mut table = TranspositionTable::new(1);
+    table.store(KEY_A, 1, 12, ValType::Exact, None);
+    table.new_search();
+    // The entry of the previous search is still found.
+    assert_eq!(table.get(KEY_A).unwrap().depth(), 12);
+    table.store(KEY_B, 2, 4, ValType::Exact, None);
+    assert!(table.get(KEY_A).is_none());
+    assert_eq!(table.get(KEY_B).unwrap().eval(), 2);
+}
+
+#[test]
+fn table_key() {
+    let game = Game::default();
+    let mut other = game.clone();
+    assert_eq!(game.table_key(), other.table_key());
+    other.change_player();
+    assert_ne!(game.table_key(), other.table_key());
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot};
+use checkers_cpu::game::{AnyGame, Position, PositionHistory, Ruleset, TurkishGame};
+use common::{cell, path, sorted_paths, turkish_position as position};
+
+#[test]
+fn initial_position() {
+    let game = AnyGame::new(Ruleset::Turkish);
+    assert_eq!(game.board_size(), 8);
+    assert!((0..8).all(|x| (0..8).all(|y| game.is_playable_cell(x, y))));
+    let game = TurkishGame::new();
+    assert_eq!(game.not_empty.count_ones(), 32);
+    for name in ["a2", "h3", "a6", "h7"] {
+        assert!(!game.is_empty_cell(cell(name)), "{name}");
+    }
+    assert_eq!(game.get_moves().len(), 8);
+}
+
+#[test]
+fn move_notation() {
+    let game = TurkishGame::new();
+    let mut notations: Vec<String> = game.get_moves().iter().map(|m| game.move_notation(m)).collect();
+    notations.sort();
+    assert_eq!(notations, ["a3-a4", "b3-b4", "c3-c4", "d3-d4", "e3-e4", "f3-f4", "g3-g4", "h3-h4"]);
+    let game = position(&["d4"], &["e4", "g4", "d5"]);
+    assert_eq!(game.move_notation(&game.get_moves()[0]), "d4:f4:h4");
+}
+
+#[test]
+fn men_never_move_backwards() {
+    let game = position(&["d4"], &["h8"]);
+    let mut expected = vec![path(&["d4", "d5"]), path(&["d4", "c4"]), path(&["d4", "e4"])];
+    expected.sort();
+    assert_eq!(sorted_paths(&game), expected);
+}
+
+#[test]
+fn majority_capture() {
+    let game = position(&["d4"], &["e4", "g4", "d5"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["d4", "f4", "h4"])]);
+}
+
+#[test]
+fn king_doesnt_turn_back() {
+    let game = position(&["Kd4"], &["d6", "d2"]);
+    let mut expected = vec![path(&["d4", "d7"]), path(&["d4", "d8"]), path(&["d4", "d1"])];
+    expected.sort();
+    assert_eq!(sorted_paths(&game), expected);
+}
+
+#[test]
+fn promotion_at_the_end_of_capture() {
+    let mut game = position(&["b6"], &["b7", "c8"]);
+    assert_eq!(sorted_paths(&game), vec![path(&["b6", "b8", "d8"])]);
+    game.make_move(game.find_move(&path(&["b6", "b8", "d8"])).unwrap());
+    assert_eq!(game.not_empty.count_ones(), 1);
+    assert!(game.is_queen_checker(cell("d8")));
+}
+
+#[test]
+fn bot_plays_turkish() {
+    let game = AnyGame::Turkish(position(&["d4", "a1"], &["e4", "g4", "d5", "h8"]));
+    let mut bot: NegaScoutBot<AnyGame> = NegaScoutBot::new(3);
+    bot.start_search(game, PositionHistory::default(), 0);
+    let best_move = loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.best_move.unwrap();
+        }
+    };
+    assert_eq!(best_move.as_vec(), path(&["d4", "f4", "h4"]));
+}
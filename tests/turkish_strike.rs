@@ -0,0 +1,58 @@
+mod common;
+
+use checkers_cpu::game::{Game, Position};
+use common::{cell, path, position};
+
+fn capture_paths(game: &Game) -> Vec<Vec<i8>> {
+    let mut paths: Vec<Vec<i8>> = game.get_moves().into_iter().map(|m| {
+        assert!(m.is_capture());
+        m.as_vec()
+    }).collect();
+    paths.sort();
+    paths
+}
+
+// a1:e5:c7:a5 captures c3, d6 and b6. Without the Turkish strike rule c3 would already be gone
+// and the king could continue with a5:e1 through its cell, capturing d2 as well.
+#[test]
+fn captured_checker_blocks_king() {
+    let game = position(&["Ka1"], &["c3", "d6", "b6", "d2"]);
+    let paths = capture_paths(&game);
+    assert!(paths.contains(&path(&["a1", "e5", "c7", "a5"])));
+    assert!(!paths.contains(&path(&["a1", "e5", "c7", "a5", "e1"])));
+    assert!(paths.iter().all(|p| !p.contains(&cell("e1"))));
+}
+
+// After a1:d4:a7 the king stands on the diagonal of the captured b6. It can't pass over b6 again,
+// so the sequence can't be extended to capture e3.
+#[test]
+fn captured_checker_is_not_passed_twice() {
+    let game = position(&["Ka1"], &["c3", "b6", "e3"]);
+    let paths = capture_paths(&game);
+    assert!(paths.contains(&path(&["a1", "d4", "a7"])));
+    assert!(!paths.contains(&path(&["a1", "d4", "a7", "f2"])));
+    assert!(!paths.contains(&path(&["a1", "d4", "a7", "g1"])));
+}
+
+#[test]
+fn captured_checkers_are_removed_after_sequence() {
+    let mut game = position(&["Ka1"], &["c3", "d6", "b6", "d2"]);
+    game.make_move(game.find_move(&path(&["a1", "e5", "c7", "a5"])).unwrap());
+    assert_eq!(game.captured, 0);
+    assert!(!game.is_empty_cell(cell("a5")));
+    assert!(!game.is_empty_cell(cell("d2")));
+    for name in ["a1", "c3", "d6", "b6"] {
+        assert!(game.is_empty_cell(cell(name)), "{name}");
+    }
+}
+
+#[test]
+fn partial_capture_keeps_captured_checkers_on_board() {
+    let mut game = position(&["Ka1"], &["c3", "d6", "b6", "d2"]);
+    game.make_cutting_move(cell("a1"), cell("e5")).unwrap();
+    game.make_cutting_move(cell("e5"), cell("c7")).unwrap();
+    assert!(!game.is_empty_cell(cell("c3")));
+    assert!(!game.is_empty_cell(cell("d6")));
+    let next: Vec<Vec<i8>> = game.get_cuts_from_cell(cell("c7")).into_iter().map(|m| m.as_vec()).collect();
+    assert_eq!(next, vec![path(&["c7", "a5"])]);
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use checkers_cpu::bot::{Bot, BotState, NegaScoutBot};
+use checkers_cpu::game::{AnyGame, Game, Move, MoveError, Piece, Position, PositionError, PositionHistory, Ruleset};
+use common::{cell, position};
+
+#[test]
+fn played_positions_are_valid() {
+    let mut rng = fastrand::Rng::with_seed(21);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..10 {
+            let mut game = Game::new(ruleset);
+            for _ in 0..100 {
+                assert_eq!(game.validate(), Ok(()), "{ruleset}\n{game}");
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn every_violation_is_reported() {
+    let mut game = position(&["c3", "b8"], &["d6", "a1"]);
+    game.is_white |= 1 << cell("e3");
+    game.is_queen |= 1 << cell("g3") | 1 << cell("g8");
+    game.not_empty |= 1 << cell("g8");
+    game.captured = 1 << cell("d6");
+    game.eval_white += 1;
+    let Err(errors) = game.validate() else { panic!() };
+    let expected_eval = game.compute_eval_white();
+    assert_eq!(
+        errors,
+        [
+            PositionError::WhiteBitOnEmptyCell("e3".to_string()),
+            PositionError::QueenBitOnEmptyCell("g3".to_string()),
+            PositionError::NotPlayableCell("g8".to_string()),
+            PositionError::PawnOnPromotionRow("a1".to_string()),
+            PositionError::PawnOnPromotionRow("b8".to_string()),
+            PositionError::UnfinishedCapture,
+            PositionError::WrongEvaluation { expected: expected_eval, actual: game.eval_white },
+            PositionError::WrongKey { expected: game.compute_key(), actual: game.key() },
+        ]
+    );
+    assert_eq!(errors[4].to_string(), "pawn on the promotion row at b8");
+}
+
+#[test]
+fn other_position_types_rulesets() {
+    for ruleset in [Ruleset::Turkish, Ruleset::International] {
+        let mut game = Game::default();
+        game.ruleset = ruleset;
+        assert_eq!(game.validate(), Err(vec![PositionError::UnsupportedRuleset(ruleset)]));
+    }
+}
+
+#[test]
+fn try_make_move() {
+    let mut game = Game::default();
+    let other = Game::new(Ruleset::Italian);
+    let m = other.get_moves()[0];
+    assert_eq!(game.try_make_move(m).err(), Some(MoveError::IllegalMove(m)));
+    assert!(game == Game::default());
+    let m = game.get_moves()[0];
+    let undo = game.try_make_move(m).unwrap();
+    game.unmake_move(undo);
+    assert!(game == Game::default());
+}
+
+#[test]
+fn try_make_move_with_capture_built_by_hand() {
+    // g1:d4:b6 is generated after the longer g1:d4:f6:d8.
+    let mut game = Game::from_fen("W:WK32,K1:B25,15,7,22,14,8,12,23", Ruleset::Russian).unwrap();
+    let start = game.clone();
+    let mut m = Move::new_capture(cell("g1"), Piece::Queen);
+    m.push_capture(cell("d4"), cell("e3"));
+    m.push_capture(cell("b6"), cell("c5"));
+    let undo = game.try_make_move(m).unwrap();
+    assert!(game.is_queen_checker(cell("b6")));
+    assert!(game.is_empty_cell(cell("e3")) && game.is_empty_cell(cell("c5")));
+    game.unmake_move(undo);
+    assert!(game == start);
+    // The captured checkers must match too.
+    let mut m = Move::new_capture(cell("g1"), Piece::Queen);
+    m.push_capture(cell("d4"), cell("f2"));
+    assert_eq!(game.try_make_move(m).err(), Some(MoveError::IllegalMove(m)));
+}
+
+// Every jump of the generated captures can be made step by step, other jumps are refused.
+#[test]
+fn capture_steps() {
+    let mut rng = fastrand::Rng::with_seed(22);
+    for ruleset in Ruleset::ALL {
+        for _ in 0..10 {
+            let mut game = AnyGame::new(ruleset);
+            for _ in 0..100 {
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                for m in moves.iter().filter(|m| m.is_capture()) {
+                    let mut steps = game.clone();
+                    for jump in m.path().windows(2) {
+                        assert_eq!(steps.make_capture_step(jump[0], jump[1]), Ok(()), "{ruleset}: {m:?}\n{game}");
+                    }
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+
+    let mut game = position(&["c3", "Ka1"], &["d4", "f6", "b2"]);
+    let refused = |game: &mut Game, from: i8, to: i8| {
+        let before = game.clone();
+        assert_eq!(game.make_capture_step(from, to), Err(MoveError::IllegalJump(from, to)), "{from}-{to}");
+        assert!(*game == before);
+    };
+    // Not diagonal, too far for a pawn, two checkers, enemy checker, no jump, nothing taken, occupied landing.
+    for (from, to) in [("c3", "c5"), ("c3", "g7"), ("a1", "e5"), ("d4", "b2"), ("c3", "b4"), ("c3", "a5"), ("a1", "c3")] {
+        refused(&mut game, cell(from), cell(to));
+    }
+    refused(&mut game, -1, 70);
+    game.make_capture_step(cell("c3"), cell("e5")).unwrap();
+    // The taken checker can't be jumped again.
+    refused(&mut game, cell("e5"), cell("c3"));
+    game.make_capture_step(cell("e5"), cell("g7")).unwrap();
+}
+
+#[test]
+fn bot_without_moves() {
+    let game = position(&[], &["h8"]);
+    let mut bot: NegaScoutBot = NegaScoutBot::new(4);
+    bot.start_search(game, PositionHistory::default(), 0);
+    let result = loop {
+        if let BotState::Finished(result) = bot.poll() {
+            break result.clone();
+        }
+    };
+    assert_eq!(result.best_move, None);
+}
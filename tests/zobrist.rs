@@ -0,0 +1,82 @@
+mod common;
+
+use checkers_cpu::game::{Game, Position, Ruleset};
+use common::{cell, path, position};
+
+fn play(game: &mut Game, from: &str, to: &str) {
+    game.make_move(game.find_move(&[cell(from), cell(to)]).unwrap());
+    game.change_player();
+}
+
+// Plays random games and compares the incremental key with the one computed from scratch
+// after every move, every jump of a capture and every take back.
+#[test]
+fn incremental_key_matches_computed() {
+    let mut rng = fastrand::Rng::with_seed(5);
+    for ruleset in Ruleset::ALL.into_iter().filter(|r| r.is_played_by_game()) {
+        for _ in 0..50 {
+            let mut game = Game::new(ruleset);
+            assert_eq!(game.key(), game.compute_key());
+            for _ in 0..150 {
+                let moves = game.get_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                for m in &moves {
+                    let key = game.key();
+                    let undo = game.make_move(*m);
+                    assert_eq!(game.key(), game.compute_key(), "{ruleset}: {m:?}");
+                    game.change_player();
+                    assert_eq!(game.key(), game.compute_key(), "{ruleset}: {m:?}");
+                    game.unmake_move(undo);
+                    assert_eq!(game.key(), key, "{ruleset}: {m:?}");
+
+                    if m.is_capture() {
+                        let mut steps = game.clone();
+                        for jump in m.path().windows(2) {
+                            steps.make_capture_step(jump[0], jump[1]).unwrap();
+                            assert_eq!(steps.key(), steps.compute_key(), "{ruleset}: {m:?}");
+                        }
+                    }
+                }
+                game.make_move(moves[rng.usize(..moves.len())]);
+                game.change_player();
+            }
+        }
+    }
+}
+
+#[test]
+fn transpositions_have_the_same_key() {
+    let mut first = Game::default();
+    play(&mut first, "c3", "b4");
+    play(&mut first, "f6", "g5");
+    play(&mut first, "g3", "f4");
+    let mut second = Game::default();
+    play(&mut second, "g3", "f4");
+    play(&mut second, "f6", "g5");
+    play(&mut second, "c3", "b4");
+    assert_eq!(first.key(), second.key());
+    assert_ne!(first.key(), Game::default().key());
+}
+
+#[test]
+fn key_depends_on_player_and_piece_kind() {
+    let game = position(&["c3"], &["h8"]);
+    let mut other_player = game.clone();
+    other_player.change_player();
+    assert_ne!(game.key(), other_player.key());
+    assert_eq!(other_player.key(), other_player.compute_key());
+
+    let king = position(&["Kc3"], &["h8"]);
+    assert_ne!(game.key(), king.key());
+
+    // Captured checkers are not part of the key until the move ends.
+    let mut game = position(&["c3"], &["d4", "f6"]);
+    game.make_capture_step(cell("c3"), cell("e5")).unwrap();
+    let mut expected = position(&["e5"], &["f6"]);
+    assert_eq!(game.key(), expected.key());
+    game.make_move(game.find_move(&path(&["e5", "g7"])).unwrap());
+    expected.make_move(expected.find_move(&path(&["e5", "g7"])).unwrap());
+    assert_eq!(game.key(), expected.key());
+}